@@ -0,0 +1,73 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{alphabet, Generator, Sampling};
+
+fn rng() -> Xoshiro256PlusPlus {
+    Xoshiro256PlusPlus::seed_from_u64(0)
+}
+
+fn hex(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hex");
+    let mut gen = Generator::new(32, &alphabet::HEX, rng());
+    group.bench_function("alphabet", |b| b.iter(|| black_box(gen.gen())));
+    let mut gen = Generator::with_random(rng());
+    group.bench_function("hex_fast", |b| b.iter(|| black_box(gen.hex_fast(32))));
+    group.finish();
+}
+
+fn default(c: &mut Criterion) {
+    let mut gen = Generator::with_random(rng());
+    c.bench_function("default", |b| b.iter(|| black_box(gen.gen())));
+}
+
+fn string_targets(c: &mut Criterion) {
+    let mut group = c.benchmark_group("string_targets");
+    let mut gen = Generator::with_random(rng());
+    let mut out = String::with_capacity(21);
+    group.bench_function("write_to", |b| {
+        b.iter(|| {
+            out.clear();
+            gen.write_to(&mut out).unwrap();
+            black_box(&out);
+        })
+    });
+    group.bench_function("push_to", |b| {
+        b.iter(|| {
+            out.clear();
+            gen.push_to(&mut out);
+            black_box(&out);
+        })
+    });
+    let emoji = randoid::Alphabet::new(['🦀', '🐍', '🐪', '🐹']);
+    let mut emoji_gen = Generator::new(21, &emoji, rng());
+    let mut emoji_out = String::with_capacity(21 * 4);
+    group.bench_function("push_to_non_ascii", |b| {
+        b.iter(|| {
+            emoji_out.clear();
+            emoji_gen.push_to(&mut emoji_out);
+            black_box(&emoji_out);
+        })
+    });
+    let mut bytes = Vec::with_capacity(21);
+    group.bench_function("extend_bytes", |b| {
+        b.iter(|| {
+            bytes.clear();
+            gen.extend_bytes(&mut bytes);
+            black_box(&bytes);
+        })
+    });
+    group.finish();
+}
+
+fn sampling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sampling");
+    for (name, sampling) in [("bytes", Sampling::Bytes), ("uniform", Sampling::Uniform)] {
+        let mut gen = Generator::new(22, &alphabet::BASE58, rng()).sampling(sampling);
+        group.bench_function(name, |b| b.iter(|| black_box(gen.gen())));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, default, hex, string_targets, sampling);
+criterion_main!(benches);
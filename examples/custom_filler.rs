@@ -0,0 +1,65 @@
+//! Using a custom source of randomness with a [`Generator`].
+//!
+//! Any [`RngCore`] can be used as the source of random data. This shows a deterministic
+//! counter, which produces the same ids every time it is run, and a closure using
+//! [`FnRng`].
+//!
+//! Run with `cargo run --example custom_filler`.
+
+use rand::RngCore;
+use randoid::{alphabet::HEX, rngs::FnRng, Generator};
+
+/// A deterministic "random" source that counts up from a starting value
+///
+/// This is obviously not random, but it is useful for reproducible ids in tests.
+struct Counter(u8);
+
+impl RngCore for Counter {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for b in dest {
+            *b = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+}
+
+fn main() {
+    let mut gen = Generator::new(8, &HEX, Counter(0));
+    let first = gen.gen();
+    let second = gen.gen();
+    println!("counter: {first} {second}");
+    assert_eq!(first, "01234567");
+    assert_eq!(second, "89abcdef");
+
+    // Starting from the same state gives the same ids again.
+    let mut again = Generator::new(8, &HEX, Counter(0));
+    assert_eq!(again.gen(), first);
+
+    // A closure can be used instead of implementing `RngCore`.
+    // This one is a simple xorshift, seeded with a constant.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let xorshift = FnRng::new(move |buf: &mut [u8]| {
+        for b in buf {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *b = state as u8;
+        }
+    });
+    let mut gen = Generator::with_random(xorshift).size(12);
+    for _ in 0..3 {
+        println!("closure: {}", gen.gen());
+    }
+}
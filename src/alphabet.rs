@@ -2,13 +2,34 @@
 //!
 //! Inlcuding the default alphabet.
 
+use core::any::Any;
+use core::fmt;
+use core::str::FromStr;
+
+use rand::Rng;
+
+use crate::Generator;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{string::String, vec::Vec};
+
+pub mod presets;
+
+pub use presets::*;
+
 /// Type for an alphabet to use for generating ids
 ///
 /// It has a fixed length, because that can provide the compiler
 /// with more optimization opportunities, and in almost all cases
 /// the alphabet used will be a constant anyway.
 #[derive(Debug)]
-pub struct Alphabet<const N: usize = 64>(pub(crate) [char; N]);
+pub struct Alphabet<const N: usize = 64> {
+    pub(crate) chars: [char; N],
+    /// Length in bytes of the shortest character when encoded as UTF-8
+    min_len: usize,
+    /// Length in bytes of the longest character when encoded as UTF-8
+    max_len: usize,
+}
 
 /// Type for alphabet with 16 possible characters
 pub type HexAlphabet = Alphabet<16>;
@@ -26,6 +47,44 @@ impl Default for &'static HexAlphabet {
 }
 
 impl<const N: usize> Alphabet<N> {
+    /// The number of characters in the alphabet
+    pub const LEN: usize = N;
+
+    /// The largest valid index into the alphabet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::HexAlphabet;
+    /// assert_eq!(HexAlphabet::MAX_INDEX, 15);
+    /// ```
+    pub const MAX_INDEX: u8 = (N - 1) as u8;
+
+    /// The number of characters in the alphabet
+    ///
+    /// This can be used in const contexts, for example to compute the size of a buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::alphabet::{DEFAULT, HEX};
+    ///
+    /// const L: usize = DEFAULT.len();
+    /// assert_eq!(L, 64);
+    /// let counts = [0u32; HEX.len()];
+    /// assert_eq!(counts.len(), 16);
+    /// ```
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the alphabet has no characters
+    ///
+    /// This is always false, since empty alphabets can't be created.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
     /// Create a new alphabe from a set of characters
     ///
     /// The length of the array should be at least 1 and at most `u8::MAX`.
@@ -36,16 +95,18 @@ impl<const N: usize> Alphabet<N> {
     ///
     /// Panics if:
     /// - the number of character is greater than the maximum value of a u8,
-    /// since no possible random byte would be able to map to some values.
+    ///   since no possible random byte would be able to map to some values.
     /// - the alphabet is empty, since nothing can be generated with an empty alphabet
-    /// - the number of characters isn't a power of 2, because the implementation assumes a power
-    /// of 2 size.
+    ///
+    /// The number of characters doesn't need to be a power of 2, but generating ids is somewhat
+    /// faster if it is, since no random data needs to be discarded.
     ///
     /// # Examples
     ///
     /// ```
     /// # use randoid::Alphabet;
     /// let alph = Alphabet::new(['1', '2', '3', '4']);
+    /// let alph = Alphabet::new(['a', 'b', 'c']);
     /// ```
     ///
     /// The following would panic:
@@ -61,46 +122,806 @@ impl<const N: usize> Alphabet<N> {
     /// let alph = Alphabet::new(c);
     /// ```
     ///
-    /// ```should_panic
+    #[track_caller]
+    pub const fn new(chars: [char; N]) -> Self {
+        match Self::try_new(chars) {
+            Ok(alphabet) => alphabet,
+            Err(InvalidAlphabet::Empty) => panic!("Alphabet cannot be empty"),
+            Err(InvalidAlphabet::TooLong) => panic!("The alphabet cannot be longer than a `u8`"),
+            Err(InvalidAlphabet::WrongLength { .. } | InvalidAlphabet::Duplicate(_)) => {
+                unreachable!()
+            }
+        }
+    }
+
+    /// Create a new alphabet from a set of characters, or return an error if it isn't valid
+    ///
+    /// This checks the same conditions as [`Alphabet::new`], but returns an error instead of
+    /// panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::{Alphabet, InvalidAlphabet};
+    /// assert!(Alphabet::try_new(['1', '2', '3', '4']).is_ok());
+    /// assert_eq!(Alphabet::try_new([]).unwrap_err(), InvalidAlphabet::Empty);
+    /// assert_eq!(Alphabet::try_new(['0'; 256]).unwrap_err(), InvalidAlphabet::TooLong);
+    /// ```
+    pub const fn try_new(chars: [char; N]) -> Result<Self, InvalidAlphabet> {
+        if N == 0 {
+            return Err(InvalidAlphabet::Empty);
+        }
+        if N > u8::MAX as usize {
+            return Err(InvalidAlphabet::TooLong);
+        }
+        let mut min_len = 4;
+        let mut max_len = 0;
+        let mut i = 0;
+        while i < N {
+            let len = chars[i].len_utf8();
+            if len < min_len {
+                min_len = len;
+            }
+            if len > max_len {
+                max_len = len;
+            }
+            i += 1;
+        }
+        Ok(Alphabet {
+            chars,
+            min_len,
+            max_len,
+        })
+    }
+
+    /// Create an alphabet from a contiguous range of characters, including both `start` and `end`
+    ///
+    /// See [`char_range`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range doesn't contain exactly `N` characters, and under the same conditions
+    /// as [`Alphabet::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
     /// # use randoid::Alphabet;
-    /// let alph = Alphabet::new(['1', '2', '3']);
+    /// let alphabet = Alphabet::<16>::from_range('a', 'p');
+    /// assert!(alphabet.validate("abcdefghijklmnop"));
+    /// assert!(!alphabet.contains('q'));
     /// ```
+    #[track_caller]
+    pub const fn from_range(start: char, end: char) -> Self {
+        Self::new(char_range(start, end))
+    }
+
+    /// Create a new alphabet by applying `f` to each character of this one
+    ///
+    /// # Panics
     ///
+    /// Panics if `f` maps two characters to the same character. See [`Alphabet::try_map`]
+    /// for a version that returns an error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::alphabet::{HEX, HEX_UPPER};
+    ///
+    /// let upper = HEX.map(|c| c.to_ascii_uppercase());
+    /// assert_eq!(upper.as_chars(), HEX_UPPER.as_chars());
+    /// ```
     #[track_caller]
-    pub const fn new(chars: [char; N]) -> Self {
-        assert!(
-            N.is_power_of_two(),
-            "Alphabet must have a length that is a power of two"
-        );
-        assert!(N != 0, "Alphabet cannot be empty");
-        assert!(
-            N <= u8::max_value() as usize,
-            "The alphabet cannot be longer than a `u8`"
-        );
-        Alphabet(chars)
-    }
-}
-
-/// Default alphabet for randoid
-///
-/// This alphabet that is safe to use in a url and uses 64 characters.
-///
-/// This is the default value for an alphabet of length 64
-pub const DEFAULT: Alphabet = Alphabet([
-    '_', '-', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
-    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
-    'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
-]);
-
-/// Standard hexadecimal alphabet with lowercase letters
-///
-/// This is the default value for an alphabet of length 16
-pub const HEX: Alphabet<16> = Alphabet([
-    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
-]);
-
-/// Standard hexadecimal alphabet with upercase letters
-pub const HEX_UPPER: Alphabet<16> = Alphabet([
-    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
-]);
+    pub fn map(&self, f: impl FnMut(char) -> char) -> Self {
+        match self.try_map(f) {
+            Ok(alphabet) => alphabet,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Create a new alphabet by applying `f` to each character of this one, or return an
+    /// error if the result isn't a valid alphabet
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlphabet::Duplicate`] if `f` maps two characters to the same character,
+    /// since that would make some characters more likely than others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{InvalidAlphabet, alphabet::{DEFAULT, HEX}};
+    ///
+    /// let shifted = HEX.try_map(|c| if c.is_ascii_digit() { c } else { (c as u8 + 6) as char });
+    /// assert_eq!(shifted.unwrap().as_chars()[10..], ['g', 'h', 'i', 'j', 'k', 'l']);
+    ///
+    /// let lower = DEFAULT.try_map(|c| c.to_ascii_lowercase());
+    /// assert_eq!(lower.unwrap_err(), InvalidAlphabet::Duplicate('a'));
+    /// ```
+    pub fn try_map(&self, mut f: impl FnMut(char) -> char) -> Result<Self, InvalidAlphabet> {
+        let mut chars = self.chars;
+        for c in &mut chars {
+            *c = f(*c);
+        }
+        for (i, c) in chars.iter().enumerate() {
+            if chars[..i].contains(c) {
+                return Err(InvalidAlphabet::Duplicate(*c));
+            }
+        }
+        Self::try_new(chars)
+    }
+
+    /// Length in bytes of the shortest character in the alphabet, when encoded as UTF-8
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::{Alphabet, alphabet::DEFAULT};
+    /// assert_eq!(DEFAULT.min_char_len(), 1);
+    /// assert_eq!(Alphabet::new(['é', '🦀']).min_char_len(), 2);
+    /// ```
+    pub const fn min_char_len(&self) -> usize {
+        self.min_len
+    }
+
+    /// Length in bytes of the longest character in the alphabet, when encoded as UTF-8
+    ///
+    /// This is computed when the alphabet is created, and can be used to find
+    /// an upper bound on how many bytes an id needs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::{Alphabet, alphabet::DEFAULT};
+    /// assert_eq!(DEFAULT.max_char_len(), 1);
+    /// assert_eq!(Alphabet::new(['a', 'é']).max_char_len(), 2);
+    /// ```
+    pub const fn max_char_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Returns true if all characters in the alphabet are ASCII
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::{Alphabet, alphabet::DEFAULT};
+    /// assert!(DEFAULT.is_ascii());
+    /// assert!(!Alphabet::new(['a', 'b', 'c', 'é']).is_ascii());
+    /// ```
+    pub const fn is_ascii(&self) -> bool {
+        self.max_len == 1
+    }
+
+    /// Get the characters of the alphabet, in order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::alphabet::HEX;
+    /// assert_eq!(HEX.as_chars()[10], 'a');
+    /// ```
+    pub const fn as_chars(&self) -> &[char; N] {
+        &self.chars
+    }
+
+    /// Returns true if `c` is one of the characters of the alphabet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::alphabet::HEX;
+    /// assert!(HEX.contains('a'));
+    /// assert!(!HEX.contains('A'));
+    /// ```
+    pub fn contains(&self, c: char) -> bool {
+        self.chars.contains(&c)
+    }
+
+    /// Check if `id` could have been generated from this alphabet
+    ///
+    /// That is, every character of `id` is in the alphabet. This doesn't check the length of `id`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::alphabet::HEX;
+    /// assert!(HEX.validate("0123abcd"));
+    /// assert!(!HEX.validate("0123ABCD"));
+    /// ```
+    pub fn validate(&self, id: &str) -> bool {
+        id.chars().all(|c| self.contains(c))
+    }
+
+    /// Check the parity character of an id created with [`Generator::gen_with_parity_bit`]
+    ///
+    /// Returns true if the last character of `id` is the character at the sum of the positions
+    /// of the other characters, modulo the length of the alphabet. Returns false if `id` is
+    /// empty, or contains characters that aren't in the alphabet. This doesn't check the length
+    /// of `id`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::alphabet::HEX;
+    /// assert!(HEX.verify_parity("12a0d"));
+    /// assert!(!HEX.verify_parity("12a0e"));
+    /// // Swapping two characters isn't detected
+    /// assert!(HEX.verify_parity("21a0d"));
+    /// assert!(HEX.verify_parity("0"));
+    /// assert!(!HEX.verify_parity(""));
+    /// ```
+    pub fn verify_parity(&self, id: &str) -> bool {
+        let mut sum = 0;
+        let mut last = None;
+        for c in id.chars() {
+            let Some(idx) = self.chars.iter().position(|&x| x == c) else {
+                return false;
+            };
+            if let Some(prev) = last.replace(idx) {
+                sum = (sum + prev) % N;
+            }
+        }
+        last == Some(sum)
+    }
+
+    /// Split the characters of the alphabet into ASCII lowercase letters, ASCII uppercase
+    /// letters, and everything else
+    ///
+    /// Returns `(lowercase, uppercase, other)`, with the characters of each group in the same
+    /// order as in the alphabet. A group can be turned back into an alphabet with
+    /// `Alphabet::try_from`, as long as it isn't empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Alphabet, alphabet::DEFAULT};
+    ///
+    /// let (lower, upper, other) = DEFAULT.split_ascii_case();
+    /// assert_eq!(lower.len(), 26);
+    /// assert_eq!(upper.len(), 26);
+    /// assert!(lower.iter().all(|c| c.is_ascii_lowercase()));
+    /// assert!(upper.iter().all(|c| c.is_ascii_uppercase()));
+    /// assert_eq!(other.iter().collect::<String>(), "_-0123456789");
+    ///
+    /// let lower = Alphabet::<26>::try_from(&lower[..]).unwrap();
+    /// assert!(lower.contains('q'));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn split_ascii_case(&self) -> (Vec<char>, Vec<char>, Vec<char>) {
+        let mut lower = Vec::new();
+        let mut upper = Vec::new();
+        let mut other = Vec::new();
+        for &c in &self.chars {
+            match c {
+                'a'..='z' => lower.push(c),
+                'A'..='Z' => upper.push(c),
+                _ => other.push(c),
+            }
+        }
+        (lower, upper, other)
+    }
+
+    /// Build a table that maps each ASCII byte to its index in the alphabet
+    ///
+    /// Entry `b` of the table is the index of the character `b` in the alphabet, or `0xFF` if
+    /// it isn't in the alphabet. This is useful for quickly validating or decoding ids one byte
+    /// at a time. Since the alphabet has at most 255 characters, `0xFF` is never a valid index.
+    ///
+    /// Non-ASCII characters in the alphabet have no entry in the table. If a character appears
+    /// more than once, its first index is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::alphabet::HEX;
+    ///
+    /// const TABLE: [u8; 128] = HEX.ascii_lookup_table();
+    /// assert_eq!(TABLE[b'0' as usize], 0);
+    /// assert_eq!(TABLE[b'a' as usize], 10);
+    /// assert_eq!(TABLE[b'f' as usize], 15);
+    /// assert_eq!(TABLE[b'g' as usize], 0xFF);
+    /// assert_eq!(TABLE[b'A' as usize], 0xFF);
+    /// ```
+    pub const fn ascii_lookup_table(&self) -> [u8; 128] {
+        let mut table = [0xFF; 128];
+        let mut i = 0;
+        while i < N {
+            let c = self.chars[i] as usize;
+            if c < 128 && table[c] == 0xFF {
+                table[c] = i as u8;
+            }
+            i += 1;
+        }
+        table
+    }
+
+    /// Write `value` to `out`, using the alphabet as the digits of a base `N` number
+    ///
+    /// The most significant digit is written first, and no leading "zeros" (the first character
+    /// of the alphabet) are written, except for the value 0, which is written as a single
+    /// character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet has fewer than 2 characters, since a base 1 number can't be
+    /// written with digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::alphabet::HEX;
+    /// let mut out = String::new();
+    /// HEX.write_u128(0xbeef, &mut out).unwrap();
+    /// assert_eq!(out, "beef");
+    /// ```
+    #[track_caller]
+    pub fn write_u128<W: fmt::Write + ?Sized>(&self, mut value: u128, out: &mut W) -> fmt::Result {
+        assert!(N >= 2, "can't write a number in base {N}");
+        // The largest number of digits we could need is for base 2
+        let mut digits = [0u8; 128];
+        let mut len = 0;
+        loop {
+            // N is at most u8::MAX, so the remainder always fits in a u8
+            digits[len] = (value % N as u128) as u8;
+            len += 1;
+            value /= N as u128;
+            if value == 0 {
+                break;
+            }
+        }
+        for &d in digits[..len].iter().rev() {
+            out.write_char(self.chars[d as usize])?;
+        }
+        Ok(())
+    }
+
+    /// Write `value` as exactly `width` digits, padded with the first character of the alphabet
+    ///
+    /// If `value` needs more than `width` digits, only the least significant `width` digits
+    /// are written. Use [`Alphabet::fixed_width_capacity`] to check that it fits.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) fn write_u128_fixed<W: fmt::Write + ?Sized>(
+        &self,
+        value: u128,
+        width: usize,
+        out: &mut W,
+    ) -> fmt::Result {
+        for i in (0..width).rev() {
+            let digit = u32::try_from(i)
+                .ok()
+                .and_then(|i| (N as u128).checked_pow(i))
+                .map_or(0, |place| (value / place) % N as u128);
+            out.write_char(self.chars[digit as usize])?;
+        }
+        Ok(())
+    }
+
+    /// The number of distinct values that can be written with `width` digits, or `None` if
+    /// every `u128` fits
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) fn fixed_width_capacity(width: usize) -> Option<u128> {
+        u32::try_from(width)
+            .ok()
+            .and_then(|width| (N as u128).checked_pow(width))
+    }
+
+    /// Encode `value` as a string, using the alphabet as the digits of a base `N` number
+    ///
+    /// See [`Alphabet::write_u128`] for details, and [`Alphabet::decode_u128`] for the inverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet has fewer than 2 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::alphabet::{HEX, DEFAULT};
+    /// assert_eq!(HEX.encode_u128(255), "ff");
+    /// assert_eq!(DEFAULT.encode_u128(0), "_");
+    /// assert_eq!(DEFAULT.encode_u128(64), "-_");
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[track_caller]
+    pub fn encode_u128(&self, value: u128) -> String {
+        let mut res = String::new();
+        self.write_u128(value, &mut res).unwrap();
+        res
+    }
+
+    /// Decode a string created with [`Alphabet::encode_u128`] back into a number
+    ///
+    /// Returns `None` if `s` is empty, contains characters that aren't in the alphabet, or
+    /// represents a number too large for a `u128`.
+    ///
+    /// This is only a true inverse if every character in the alphabet is unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::alphabet::{HEX, DEFAULT};
+    /// for value in [0, 1, 15, 16, 0xdead_beef, u64::MAX as u128, u128::MAX] {
+    ///     assert_eq!(HEX.decode_u128(&HEX.encode_u128(value)), Some(value));
+    ///     assert_eq!(DEFAULT.decode_u128(&DEFAULT.encode_u128(value)), Some(value));
+    /// }
+    /// assert_eq!(HEX.decode_u128("xyz"), None);
+    /// assert_eq!(HEX.decode_u128(""), None);
+    /// assert_eq!(HEX.decode_u128(&"f".repeat(33)), None);
+    /// ```
+    pub fn decode_u128(&self, s: &str) -> Option<u128> {
+        if s.is_empty() {
+            return None;
+        }
+        s.chars().try_fold(0u128, |acc, c| {
+            let digit = self.chars.iter().position(|&x| x == c)?;
+            acc.checked_mul(N as u128)?.checked_add(digit as u128)
+        })
+    }
+}
+
+/// Create an array of all the characters from `start` to `end`, inclusive
+///
+/// This is useful for creating an alphabet without having to write out every character.
+///
+/// # Panics
+///
+/// Panics if the range doesn't contain exactly `N` characters, or if it includes surrogate
+/// code points (which aren't valid characters).
+///
+/// # Examples
+///
+/// ```
+/// use randoid::alphabet::char_range;
+///
+/// let letters: [char; 26] = char_range('a', 'z');
+/// assert_eq!(letters.len(), 26);
+/// assert!(('a'..='z').all(|c| letters.contains(&c)));
+/// assert!(!letters.contains(&'A'));
+/// ```
+///
+/// ```should_panic
+/// use randoid::alphabet::char_range;
+///
+/// let letters: [char; 10] = char_range('a', 'z');
+/// ```
+#[track_caller]
+pub const fn char_range<const N: usize>(start: char, end: char) -> [char; N] {
+    let (start, end) = (start as u32, end as u32);
+    assert!(
+        end >= start && (end - start) as usize + 1 == N,
+        "The range must contain exactly N characters"
+    );
+    let mut chars = ['\0'; N];
+    let mut i = 0;
+    while i < N {
+        chars[i] = match char::from_u32(start + i as u32) {
+            Some(c) => c,
+            None => panic!("The range cannot contain surrogate code points"),
+        };
+        i += 1;
+    }
+    chars
+}
+
+impl<const N: usize> From<[char; N]> for Alphabet<N> {
+    /// Create an alphabet from an array of characters
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`Alphabet::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::Alphabet;
+    /// let alphabet: Alphabet<4> = ['a', 'b', 'c', 'd'].into();
+    /// assert!(alphabet.contains('c'));
+    /// ```
+    #[track_caller]
+    fn from(chars: [char; N]) -> Self {
+        Alphabet::new(chars)
+    }
+}
+
+impl<const N: usize> From<&[char; N]> for Alphabet<N> {
+    /// Create an alphabet from a reference to an array of characters
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`Alphabet::new`].
+    #[track_caller]
+    fn from(chars: &[char; N]) -> Self {
+        Alphabet::new(*chars)
+    }
+}
+
+impl<const N: usize> TryFrom<&[char]> for Alphabet<N> {
+    type Error = InvalidAlphabet;
+
+    /// Create an alphabet from a slice of characters
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlphabet::WrongLength`] if the slice doesn't have exactly `N`
+    /// characters, and otherwise fails under the same conditions as [`Alphabet::try_new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use randoid::{Alphabet, InvalidAlphabet};
+    /// let chars: Vec<char> = ('a'..='h').collect();
+    /// let alphabet = Alphabet::<8>::try_from(&chars[..]).unwrap();
+    /// assert!(alphabet.contains('h'));
+    ///
+    /// assert_eq!(
+    ///     Alphabet::<4>::try_from(&chars[..]).unwrap_err(),
+    ///     InvalidAlphabet::WrongLength { expected: 4, actual: 8 },
+    /// );
+    /// ```
+    fn try_from(chars: &[char]) -> Result<Self, InvalidAlphabet> {
+        let chars: [char; N] = chars.try_into().map_err(|_| InvalidAlphabet::WrongLength {
+            expected: N,
+            actual: chars.len(),
+        })?;
+        Alphabet::try_new(chars)
+    }
+}
+
+/// Error for an invalid set of characters for an [`Alphabet`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidAlphabet {
+    /// The alphabet doesn't have any characters
+    Empty,
+    /// There are more characters than the maximum value of a `u8`
+    TooLong,
+    /// The number of characters doesn't match the length of the alphabet type
+    WrongLength {
+        /// The length of the alphabet type
+        expected: usize,
+        /// The number of characters given
+        actual: usize,
+    },
+    /// The same character appears more than once
+    Duplicate(char),
+}
+
+impl fmt::Display for InvalidAlphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidAlphabet::Empty => f.write_str("alphabet cannot be empty"),
+            InvalidAlphabet::TooLong => f.write_str("alphabet cannot be longer than a `u8`"),
+            InvalidAlphabet::WrongLength { expected, actual } => write!(
+                f,
+                "alphabet must have {expected} characters, but {actual} were given"
+            ),
+            InvalidAlphabet::Duplicate(c) => write!(f, "alphabet contains {c:?} more than once"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidAlphabet {}
+
+/// Error returned when an operation requires an ASCII alphabet, but the alphabet
+/// contains non-ASCII characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonAsciiAlphabet;
+
+impl fmt::Display for NonAsciiAlphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("alphabet contains non-ASCII characters")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonAsciiAlphabet {}
+
+/// The alphabets shipped with this crate, identified by name
+///
+/// This is useful for letting users pick an alphabet by name, for example in a
+/// command line argument or a configuration file.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::alphabet::Preset;
+///
+/// for &preset in Preset::ALL {
+///     assert_eq!(Preset::from_name(preset.name()), Some(preset));
+/// }
+/// assert_eq!(Preset::from_name("hex"), Some(Preset::Hex));
+/// assert_eq!(Preset::from_name("klingon"), None);
+/// ```
+///
+/// It can also be parsed from a string, ignoring case, and displayed as its name:
+///
+/// ```
+/// use randoid::alphabet::Preset;
+///
+/// assert_eq!("base58".parse::<Preset>(), Ok(Preset::Base58));
+/// assert_eq!("Hex-Upper".parse::<Preset>(), Ok(Preset::HexUpper));
+/// assert_eq!(Preset::NanoidCompat.to_string(), "nanoid");
+///
+/// let err = "base64".parse::<Preset>().unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "unknown alphabet preset, expected one of: url, nanoid, hex, hex-upper, base58"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Preset {
+    /// The url safe [`DEFAULT`] alphabet, named `url`
+    #[cfg_attr(feature = "serde", serde(rename = "url"))]
+    Url,
+    /// The [`NANOID_COMPAT`] alphabet, named `nanoid`
+    #[cfg_attr(feature = "serde", serde(rename = "nanoid"))]
+    NanoidCompat,
+    /// The lowercase [`HEX`] alphabet, named `hex`
+    #[cfg_attr(feature = "serde", serde(rename = "hex"))]
+    Hex,
+    /// The uppercase [`HEX_UPPER`] alphabet, named `hex-upper`
+    #[cfg_attr(feature = "serde", serde(rename = "hex-upper"))]
+    HexUpper,
+    /// The [`BASE58`] alphabet, named `base58`
+    #[cfg_attr(feature = "serde", serde(rename = "base58"))]
+    Base58,
+}
+
+impl Preset {
+    /// All of the presets
+    pub const ALL: &'static [Preset] = &[
+        Preset::Url,
+        Preset::NanoidCompat,
+        Preset::Hex,
+        Preset::HexUpper,
+        Preset::Base58,
+    ];
+
+    /// The name of the preset
+    pub const fn name(self) -> &'static str {
+        match self {
+            Preset::Url => "url",
+            Preset::NanoidCompat => "nanoid",
+            Preset::Hex => "hex",
+            Preset::HexUpper => "hex-upper",
+            Preset::Base58 => "base58",
+        }
+    }
+
+    /// Look up a preset by its name, ignoring ASCII case
+    ///
+    /// Returns `None` if there is no preset with that name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|p| p.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The characters of the preset's alphabet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::alphabet::Preset;
+    ///
+    /// assert_eq!(Preset::Hex.chars().len(), 16);
+    /// ```
+    pub const fn chars(self) -> &'static [char] {
+        match self {
+            Preset::Url => &DEFAULT.chars,
+            Preset::NanoidCompat => &NANOID_COMPAT.chars,
+            Preset::Hex => &HEX.chars,
+            Preset::HexUpper => &HEX_UPPER.chars,
+            Preset::Base58 => &BASE58.chars,
+        }
+    }
+
+    /// The preset's alphabet, if it has `N` characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::alphabet::Preset;
+    ///
+    /// assert!(Preset::Hex.alphabet::<16>().is_some());
+    /// assert!(Preset::Hex.alphabet::<64>().is_none());
+    /// ```
+    pub fn alphabet<const N: usize>(self) -> Option<&'static Alphabet<N>> {
+        let alphabet: &'static dyn Any = match self {
+            Preset::Url => &DEFAULT,
+            Preset::NanoidCompat => &NANOID_COMPAT,
+            Preset::Hex => &HEX,
+            Preset::HexUpper => &HEX_UPPER,
+            Preset::Base58 => &BASE58,
+        };
+        // This only succeeds if the alphabet's length is `N`
+        alphabet.downcast_ref()
+    }
+
+    /// Generate an id of `size` characters from the preset's alphabet, and write it to `out`
+    ///
+    /// This is equivalent to calling [`Generator::write_to`] on a generator that uses the
+    /// preset's alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::alphabet::Preset;
+    ///
+    /// let mut id = String::new();
+    /// Preset::HexUpper.write_id(8, rand::rng(), &mut id).unwrap();
+    /// assert_eq!(id.len(), 8);
+    /// assert!(id.chars().all(|c| matches!(c, '0'..='9' | 'A'..='F')));
+    /// ```
+    pub fn write_id<R: Rng, W: fmt::Write>(
+        self,
+        size: usize,
+        random: R,
+        out: &mut W,
+    ) -> fmt::Result {
+        match self {
+            Preset::Url => Generator::new(size, &DEFAULT, random).write_to(out),
+            Preset::NanoidCompat => Generator::new(size, &NANOID_COMPAT, random).write_to(out),
+            Preset::Hex => Generator::new(size, &HEX, random).write_to(out),
+            Preset::HexUpper => Generator::new(size, &HEX_UPPER, random).write_to(out),
+            Preset::Base58 => Generator::new(size, &BASE58, random).write_to(out),
+        }
+    }
+
+    /// Generate an id of `size` characters from the preset's alphabet, as a string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::alphabet::Preset;
+    ///
+    /// let id = Preset::from_name("hex").unwrap().gen(12, rand::rng());
+    /// assert_eq!(id.len(), 12);
+    /// assert!(id.chars().all(|c| c.is_ascii_hexdigit()));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen<R: Rng>(self, size: usize, random: R) -> String {
+        let mut res = String::with_capacity(size);
+        self.write_id(size, random, &mut res).unwrap();
+        res
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = UnknownPreset;
+
+    fn from_str(s: &str) -> Result<Self, UnknownPreset> {
+        Preset::from_name(s).ok_or(UnknownPreset)
+    }
+}
+
+/// Error returned when parsing a [`Preset`] from an unknown name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownPreset;
+
+impl fmt::Display for UnknownPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown alphabet preset, expected one of: ")?;
+        for (i, preset) in Preset::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(preset.name())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownPreset {}
@@ -0,0 +1,205 @@
+//! Alphabets for common use cases.
+//!
+//! All of these are also available directly from the [`alphabet`](super) module. General purpose
+//! alphabets, such as [`DEFAULT`] and [`HEX`], are always available. More specialized alphabets
+//! require the `presets` feature.
+
+use super::Alphabet;
+
+/// Default alphabet for randoid
+///
+/// This alphabet that is safe to use in a url and uses 64 characters.
+///
+/// This is the default value for an alphabet of length 64
+///
+/// Note that the characters are in a different order than the JavaScript nanoid library uses.
+/// See [`NANOID_COMPAT`] if that matters to you.
+pub const DEFAULT: Alphabet = Alphabet::new([
+    '_', '-', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+    'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+]);
+
+/// Standard hexadecimal alphabet with lowercase letters
+///
+/// This is the default value for an alphabet of length 16
+pub const HEX: Alphabet<16> = Alphabet::new([
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
+]);
+
+/// Standard hexadecimal alphabet with upercase letters
+pub const HEX_UPPER: Alphabet<16> = Alphabet::new([
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+]);
+
+/// Decimal digits
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::NUMERIC};
+///
+/// let pin = Generator::with_alphabet(&NUMERIC).size(6).gen();
+/// assert!(pin.chars().all(|c| c.is_ascii_digit()));
+/// ```
+pub const NUMERIC: Alphabet<10> = Alphabet::new(['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']);
+
+/// Url safe alphabet, in the same order as the JavaScript nanoid library's `urlAlphabet`
+///
+/// This contains the same characters as [`DEFAULT`], but in a different order. Since each
+/// random value is mapped to a character by its position in the alphabet, the order determines
+/// which character a given random value produces. So if you need to generate the same ids as
+/// nanoid from the same random bytes (for example, to reproduce ids from a shared seed), use
+/// this alphabet instead of [`DEFAULT`].
+///
+/// # Examples
+///
+/// ```
+/// use randoid::alphabet::{NANOID_COMPAT, DEFAULT};
+///
+/// let chars: String = NANOID_COMPAT.as_chars().iter().collect();
+/// assert_eq!(chars, "useandom-26T198340PX75pxJACKVERYMINDBUSHWOLF_GQZbfghjklqvwyzrict");
+/// assert!(DEFAULT.as_chars().iter().all(|&c| NANOID_COMPAT.contains(c)));
+/// ```
+pub const NANOID_COMPAT: Alphabet = Alphabet::new([
+    'u', 's', 'e', 'a', 'n', 'd', 'o', 'm', '-', '2', '6', 'T', '1', '9', '8', '3', '4', '0', 'P',
+    'X', '7', '5', 'p', 'x', 'J', 'A', 'C', 'K', 'V', 'E', 'R', 'Y', 'M', 'I', 'N', 'D', 'B', 'U',
+    'S', 'H', 'W', 'O', 'L', 'F', '_', 'G', 'Q', 'Z', 'b', 'f', 'g', 'h', 'j', 'k', 'l', 'q', 'v',
+    'w', 'y', 'z', 'r', 'i', 'c', 't',
+]);
+
+/// Base58 alphabet, as used by Bitcoin
+///
+/// This excludes characters that are easily confused with each other: `0`, `O`, `I`, and `l`.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::BASE58};
+///
+/// let id = Generator::with_alphabet(&BASE58).gen();
+/// assert!(!id.contains(['0', 'O', 'I', 'l']));
+/// assert!(id.chars().all(|c| c.is_ascii_alphanumeric()));
+/// ```
+pub const BASE58: Alphabet<58> = Alphabet::new([
+    '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K',
+    'L', 'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e',
+    'f', 'g', 'h', 'i', 'j', 'k', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+    'z',
+]);
+
+/// Base62 alphabet: digits, uppercase letters, and lowercase letters
+///
+/// This is commonly used for short urls. Unlike [`DEFAULT`], it doesn't contain any
+/// punctuation, so ids can be selected with a double click, and are never split by a line
+/// break.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::BASE62};
+///
+/// let id = Generator::with_alphabet(&BASE62).gen();
+/// assert!(id.chars().all(|c| c.is_ascii_alphanumeric()));
+/// assert!(!id.contains(['-', '_']));
+/// ```
+pub const BASE62: Alphabet<62> = Alphabet::new([
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I',
+    'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b',
+    'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u',
+    'v', 'w', 'x', 'y', 'z',
+]);
+
+/// Printable ASCII characters, excluding quotes and backslash
+///
+/// This has 90 characters: every printable ASCII character except space, `'`, `"`, `` ` ``,
+/// and `\`. That gives about 6.5 bits of entropy per character, so ids can be shorter than
+/// with [`DEFAULT`] for the same entropy. However, ids aren't url safe and may contain
+/// characters that are special in shells, regexes, markup, etc., so this is best suited to
+/// ids that are only used internally.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::ASCII_PRINTABLE_SAFE};
+///
+/// assert_eq!(ASCII_PRINTABLE_SAFE.len(), 90);
+/// for c in ['\'', '"', '`', '\\', ' '] {
+///     assert!(!ASCII_PRINTABLE_SAFE.contains(c));
+/// }
+///
+/// let id = Generator::with_alphabet(&ASCII_PRINTABLE_SAFE).gen();
+/// assert!(id.chars().all(|c| c.is_ascii_graphic() && !matches!(c, '\'' | '"' | '`' | '\\')));
+/// ```
+pub const ASCII_PRINTABLE_SAFE: Alphabet<90> = Alphabet::new([
+    '!', '#', '$', '%', '&', '(', ')', '*', '+', ',', '-', '.', '/', '0', '1', '2', '3', '4', '5',
+    '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', '@', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H',
+    'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '[',
+    ']', '^', '_', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p',
+    'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '{', '|', '}', '~',
+]);
+
+/// Crockford's Base32 alphabet
+///
+/// Digits and uppercase letters, excluding `I`, `L`, `O`, and `U`. This avoids characters
+/// that are easily confused with each other, or with digits, which makes it a good choice for
+/// ids that people need to read or type.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::presets::CROCKFORD};
+///
+/// let id = Generator::with_alphabet(&CROCKFORD).gen();
+/// assert!(!id.contains(['I', 'L', 'O', 'U']));
+/// assert!(id.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()));
+/// ```
+#[cfg(feature = "presets")]
+#[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+pub const CROCKFORD: Alphabet<32> = Alphabet::new([
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J',
+    'K', 'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'V', 'W', 'X', 'Y', 'Z',
+]);
+
+/// The base 32 alphabet used by [geohashes]
+///
+/// Digits and lowercase letters, except for `a`, `i`, `l`, and `o`. This is useful for ids that
+/// look like location codes. Since it has 32 characters, each character is exactly 5 bits of
+/// random data, and none is rejected.
+///
+/// [geohashes]: https://en.wikipedia.org/wiki/Geohash
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::presets::GEOHASH};
+///
+/// let id = Generator::with_alphabet(&GEOHASH).size(64).gen();
+/// assert!(!id.contains(['a', 'i', 'l', 'o']));
+/// assert!(id.chars().all(|c| c.is_ascii_digit() || c.is_ascii_lowercase()));
+/// ```
+#[cfg(feature = "presets")]
+#[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+pub const GEOHASH: Alphabet<32> = Alphabet::new([
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'j', 'k',
+    'm', 'n', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+]);
+
+/// Digits and lowercase letters
+///
+/// This is case insensitive, unlike [`DEFAULT`], so it works for ids that may be lowercased,
+/// such as hostnames.
+#[cfg(feature = "presets")]
+#[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+pub const BASE36: Alphabet<36> = Alphabet::new([
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
+    'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+]);
+
+/// The four nucleobases of DNA
+///
+/// Mostly useful for generating test data.
+#[cfg(feature = "presets")]
+#[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+pub const DNA: Alphabet<4> = Alphabet::new(['A', 'C', 'G', 'T']);
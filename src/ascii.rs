@@ -0,0 +1,222 @@
+//! Alphabets that are statically known to be ASCII.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+use core::ops::{Deref, DerefMut};
+
+use rand::Rng;
+
+use crate::alphabet::presets;
+use crate::{Alphabet, Generator, NonAsciiAlphabet};
+
+/// An [`Alphabet`] that only contains ASCII characters
+///
+/// A generator created from an `AsciiAlphabet` with [`Generator::new_ascii`] can write ids as
+/// bytes without checking the alphabet at runtime, or returning an error.
+///
+/// Each of the ASCII [presets](crate::alphabet::presets) is available as an associated constant,
+/// such as [`AsciiAlphabet::DEFAULT`] and [`AsciiAlphabet::HEX`].
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{AsciiAlphabet, NonAsciiAlphabet, Alphabet, alphabet::{DEFAULT, HEX}};
+///
+/// const URL: AsciiAlphabet = AsciiAlphabet::new(DEFAULT);
+/// const LOWER_HEX: AsciiAlphabet<16> = AsciiAlphabet::new(HEX);
+/// assert_eq!(LOWER_HEX.as_chars(), AsciiAlphabet::HEX.as_chars());
+///
+/// assert!(AsciiAlphabet::try_from(Alphabet::new(['a', 'b'])).is_ok());
+/// assert_eq!(
+///     AsciiAlphabet::try_new(Alphabet::new(['a', 'é'])).unwrap_err(),
+///     NonAsciiAlphabet,
+/// );
+/// ```
+#[derive(Debug)]
+pub struct AsciiAlphabet<const N: usize = 64>(Alphabet<N>);
+
+impl<const N: usize> AsciiAlphabet<N> {
+    /// Create an ASCII alphabet
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet contains any non-ASCII characters.
+    #[track_caller]
+    pub const fn new(alphabet: Alphabet<N>) -> Self {
+        match Self::try_new(alphabet) {
+            Ok(alphabet) => alphabet,
+            Err(_) => panic!("alphabet contains non-ASCII characters"),
+        }
+    }
+
+    /// Create an ASCII alphabet, or return an error if the alphabet contains any non-ASCII
+    /// characters
+    pub const fn try_new(alphabet: Alphabet<N>) -> Result<Self, NonAsciiAlphabet> {
+        if alphabet.is_ascii() {
+            Ok(AsciiAlphabet(alphabet))
+        } else {
+            Err(NonAsciiAlphabet)
+        }
+    }
+
+    /// Get the underlying alphabet
+    pub const fn as_alphabet(&self) -> &Alphabet<N> {
+        &self.0
+    }
+}
+
+impl AsciiAlphabet {
+    /// The [`DEFAULT`](presets::DEFAULT) alphabet
+    pub const DEFAULT: Self = Self::new(presets::DEFAULT);
+
+    /// The [`NANOID_COMPAT`](presets::NANOID_COMPAT) alphabet
+    pub const NANOID_COMPAT: Self = Self::new(presets::NANOID_COMPAT);
+}
+
+impl AsciiAlphabet<16> {
+    /// The [`HEX`](presets::HEX) alphabet
+    pub const HEX: Self = Self::new(presets::HEX);
+
+    /// The [`HEX_UPPER`](presets::HEX_UPPER) alphabet
+    pub const HEX_UPPER: Self = Self::new(presets::HEX_UPPER);
+}
+
+impl AsciiAlphabet<10> {
+    /// The [`NUMERIC`](presets::NUMERIC) alphabet
+    pub const NUMERIC: Self = Self::new(presets::NUMERIC);
+}
+
+impl AsciiAlphabet<58> {
+    /// The [`BASE58`](presets::BASE58) alphabet
+    pub const BASE58: Self = Self::new(presets::BASE58);
+}
+
+impl AsciiAlphabet<62> {
+    /// The [`BASE62`](presets::BASE62) alphabet
+    pub const BASE62: Self = Self::new(presets::BASE62);
+}
+
+impl AsciiAlphabet<90> {
+    /// The [`ASCII_PRINTABLE_SAFE`](presets::ASCII_PRINTABLE_SAFE) alphabet
+    pub const ASCII_PRINTABLE_SAFE: Self = Self::new(presets::ASCII_PRINTABLE_SAFE);
+}
+
+#[cfg(feature = "presets")]
+impl AsciiAlphabet<32> {
+    /// The [`CROCKFORD`](presets::CROCKFORD) alphabet
+    #[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+    pub const CROCKFORD: Self = Self::new(presets::CROCKFORD);
+
+    /// The [`GEOHASH`](presets::GEOHASH) alphabet
+    #[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+    pub const GEOHASH: Self = Self::new(presets::GEOHASH);
+}
+
+#[cfg(feature = "presets")]
+impl AsciiAlphabet<36> {
+    /// The [`BASE36`](presets::BASE36) alphabet
+    #[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+    pub const BASE36: Self = Self::new(presets::BASE36);
+}
+
+#[cfg(feature = "presets")]
+impl AsciiAlphabet<4> {
+    /// The [`DNA`](presets::DNA) alphabet
+    #[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+    pub const DNA: Self = Self::new(presets::DNA);
+}
+
+impl<const N: usize> TryFrom<Alphabet<N>> for AsciiAlphabet<N> {
+    type Error = NonAsciiAlphabet;
+
+    fn try_from(alphabet: Alphabet<N>) -> Result<Self, NonAsciiAlphabet> {
+        Self::try_new(alphabet)
+    }
+}
+
+impl<const N: usize> Deref for AsciiAlphabet<N> {
+    type Target = Alphabet<N>;
+
+    fn deref(&self) -> &Alphabet<N> {
+        &self.0
+    }
+}
+
+/// A [`Generator`] that uses an [`AsciiAlphabet`]
+///
+/// This dereferences to a [`Generator`], so all of its methods can be used, but it also has
+/// infallible methods for generating ids as bytes.
+///
+/// Created with [`Generator::new_ascii`].
+pub struct AsciiGenerator<'a, R, const N: usize = 64>(Generator<'a, R, N>);
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Create a new generator from an [`AsciiAlphabet`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{AsciiAlphabet, Generator, alphabet::HEX};
+    ///
+    /// let hex = AsciiAlphabet::new(HEX);
+    /// let mut gen = Generator::new_ascii(8, &hex, rand::rng());
+    /// let mut buf = [0; 8];
+    /// assert_eq!(gen.write_bytes(&mut buf), 8);
+    /// assert!(buf.iter().all(u8::is_ascii_hexdigit));
+    /// ```
+    pub fn new_ascii(
+        size: usize,
+        alphabet: &'a AsciiAlphabet<N>,
+        random: R,
+    ) -> AsciiGenerator<'a, R, N> {
+        AsciiGenerator(Generator::new(size, &alphabet.0, random))
+    }
+}
+
+impl<'a, R: Rng, const N: usize> AsciiGenerator<'a, R, N> {
+    /// Generate a new id, and write it to `out` as bytes
+    ///
+    /// This is like [`Generator::try_write_bytes`], but can't fail. Returns the number of bytes
+    /// written, which is the smaller of `out.len()` and the configured size.
+    pub fn write_bytes(&mut self, out: &mut [u8]) -> usize {
+        self.0.write_ascii_bytes(out)
+    }
+
+    /// Generate a new id as a vector of bytes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{AsciiAlphabet, Generator, alphabet::DEFAULT};
+    ///
+    /// let url = AsciiAlphabet::new(DEFAULT);
+    /// let id = Generator::new_ascii(21, &url, rand::rng()).gen_bytes();
+    /// assert_eq!(id.len(), 21);
+    /// assert!(id.is_ascii());
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_bytes(&mut self) -> Vec<u8> {
+        let mut res = vec![0; self.0.size];
+        self.write_bytes(&mut res);
+        res
+    }
+
+    /// Convert back into a plain [`Generator`]
+    pub fn into_inner(self) -> Generator<'a, R, N> {
+        self.0
+    }
+}
+
+impl<'a, R, const N: usize> Deref for AsciiGenerator<'a, R, N> {
+    type Target = Generator<'a, R, N>;
+
+    fn deref(&self) -> &Generator<'a, R, N> {
+        &self.0
+    }
+}
+
+impl<R, const N: usize> DerefMut for AsciiGenerator<'_, R, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,343 @@
+//! Plain data description of how to generate ids.
+
+use core::fmt;
+
+use rand::Rng;
+
+use crate::alphabet::{Alphabet, Preset};
+use crate::{Generator, DEFAULT_MAX_ID_BYTES, DEFAULT_SIZE};
+
+/// Configuration for a [`Generator`]
+///
+/// This is an alternative to the builder methods on [`Generator`] that is plain data,
+/// so it can be stored, and if the `serde` feature is enabled, loaded from a configuration file.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Config, Generator, alphabet::Preset};
+///
+/// let config = Config { size: 12, alphabet: Preset::Hex };
+/// let id = Generator::<_, 16>::from_config(&config, rand::rng()).unwrap().gen();
+/// assert_eq!(id.len(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Config {
+    /// Number of characters in a generated id
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_size"))]
+    pub size: usize,
+    /// The alphabet to choose characters from
+    pub alphabet: Preset,
+}
+
+impl Default for Config {
+    /// The default configuration, which is 21 characters from the url safe alphabet
+    fn default() -> Self {
+        Config {
+            size: DEFAULT_SIZE,
+            alphabet: Preset::Url,
+        }
+    }
+}
+
+/// Deserialize the size of a [`Config`], rejecting a size of zero
+#[cfg(feature = "serde")]
+fn deserialize_size<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<usize, D::Error> {
+    let size = <usize as serde::Deserialize>::deserialize(deserializer)?;
+    if size == 0 {
+        return Err(serde::de::Error::custom(ConfigError::ZeroSize));
+    }
+    Ok(size)
+}
+
+impl Config {
+    /// Check that the configuration is usable
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::ZeroSize`] if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Config, ConfigError};
+    ///
+    /// assert_eq!(Config::default().validate(), Ok(()));
+    /// let config = Config { size: 0, ..Config::default() };
+    /// assert_eq!(config.validate(), Err(ConfigError::ZeroSize));
+    /// ```
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.size == 0 {
+            return Err(ConfigError::ZeroSize);
+        }
+        Ok(())
+    }
+}
+
+impl<R: Rng, const N: usize> Generator<'static, R, N> {
+    /// Create a new generator from a [`Config`]
+    ///
+    /// Since the length of the alphabet is part of the generator's type, `N` must match the
+    /// length of the configured alphabet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration isn't valid (see [`Config::validate`]), or if the
+    /// configured alphabet doesn't have `N` characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Config, ConfigError, Generator, alphabet::Preset};
+    ///
+    /// let config = Config { size: 8, alphabet: Preset::HexUpper };
+    /// let mut gen = Generator::<_, 16>::from_config(&config, rand::rng()).unwrap();
+    /// assert!(gen.gen().chars().all(|c| matches!(c, '0'..='9' | 'A'..='F')));
+    ///
+    /// let wrong_size = Generator::<_, 64>::from_config(&config, rand::rng());
+    /// assert!(matches!(
+    ///     wrong_size,
+    ///     Err(ConfigError::AlphabetSize { expected: 64, actual: 16 })
+    /// ));
+    /// ```
+    pub fn from_config(config: &Config, random: R) -> Result<Self, ConfigError> {
+        config.validate()?;
+        let alphabet = config
+            .alphabet
+            .alphabet::<N>()
+            .ok_or(ConfigError::AlphabetSize {
+                expected: N,
+                actual: config.alphabet.chars().len(),
+            })?;
+        Ok(Generator::new(config.size, alphabet, random))
+    }
+}
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Create a new generator, like [`Generator::new`], but check that the configuration is
+    /// sensible first
+    ///
+    /// This catches misconfiguration when the generator is created, rather than when it is
+    /// used.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Generator::validate_self`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{ConfigError, Generator, alphabet::HEX};
+    ///
+    /// let mut gen = Generator::try_new(16, &HEX, rand::rng()).unwrap();
+    /// assert_eq!(gen.gen().len(), 16);
+    ///
+    /// let too_long = Generator::try_new(1 << 20, &HEX, rand::rng());
+    /// assert!(matches!(too_long, Err(ConfigError::TooLarge)));
+    /// let empty = Generator::try_new(0, &HEX, rand::rng());
+    /// assert!(matches!(empty, Err(ConfigError::ZeroSize)));
+    /// ```
+    pub fn try_new(size: usize, alphabet: &'a Alphabet<N>, random: R) -> Result<Self, ConfigError> {
+        let gen = Generator::new(size, alphabet, random);
+        gen.validate_self()?;
+        Ok(gen)
+    }
+
+    /// Take a snapshot of the size and alphabet of this generator, as a [`Config`]
+    ///
+    /// The config can be stored, and passed to [`Generator::from_config`] to create a generator
+    /// that makes the same kind of ids somewhere else. Only the size and alphabet are
+    /// included, not the rng or settings like [`Generator::sampling`].
+    ///
+    /// Returns `None` if the alphabet doesn't have the same characters, in the same order, as
+    /// one of the [`Preset`]s, since a config can only refer to an alphabet by its preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Config, Generator, alphabet::{HEX, Preset}};
+    /// use rand::SeedableRng;
+    /// use rand_xoshiro::Xoshiro256PlusPlus;
+    ///
+    /// let gen = Generator::with_alphabet(&HEX).size(12);
+    /// let config = gen.config().unwrap();
+    /// assert_eq!(config, Config { size: 12, alphabet: Preset::Hex });
+    ///
+    /// let rng = || Xoshiro256PlusPlus::seed_from_u64(7);
+    /// let mut original = Generator::new(12, &HEX, rng());
+    /// let mut rebuilt = Generator::<_, 16>::from_config(&config, rng()).unwrap();
+    /// assert_eq!(original.gen(), rebuilt.gen());
+    ///
+    /// let custom = randoid::Alphabet::new(['a', 'b']);
+    /// assert_eq!(Generator::with_alphabet(&custom).config(), None);
+    /// ```
+    pub fn config(&self) -> Option<Config> {
+        let alphabet = Preset::ALL
+            .iter()
+            .copied()
+            .find(|preset| preset.chars() == self.alphabet.as_chars())?;
+        Some(Config {
+            size: self.size,
+            alphabet,
+        })
+    }
+
+    /// Check that this generator is configured to produce sensible ids
+    ///
+    /// This is meant for asserting that a generator is sound before using it in a hot loop.
+    ///
+    /// The alphabet isn't checked, because an [`Alphabet`] can only be created with between 1
+    /// and 255 characters, and the rejection mask always covers every index into it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::ZeroSize`] if the size is zero, or [`ConfigError::TooLarge`] if an
+    /// id could be longer than [`DEFAULT_MAX_ID_BYTES`] bytes, the same limit that
+    /// [`Generator::try_gen`] uses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{ConfigError, Generator};
+    ///
+    /// assert_eq!(Generator::default().validate_self(), Ok(()));
+    /// assert_eq!(Generator::with_size(0).validate_self(), Err(ConfigError::ZeroSize));
+    ///
+    /// let too_long = Generator::with_alphabet(&randoid::alphabet::NANOID_COMPAT).size(usize::MAX);
+    /// assert_eq!(too_long.validate_self(), Err(ConfigError::TooLarge));
+    /// let emoji = randoid::Alphabet::new(['🦀', '🦞']);
+    /// let too_long = Generator::with_alphabet(&emoji).size(usize::MAX);
+    /// assert_eq!(too_long.validate_self(), Err(ConfigError::TooLarge));
+    /// ```
+    pub fn validate_self(&self) -> Result<(), ConfigError> {
+        if self.size == 0 {
+            return Err(ConfigError::ZeroSize);
+        }
+        if self.max_id_bytes() > DEFAULT_MAX_ID_BYTES {
+            return Err(ConfigError::TooLarge);
+        }
+        Ok(())
+    }
+
+    /// Number of bits of entropy in each id, counting whole bits per character
+    fn whole_entropy_bits(&self) -> u64 {
+        (N.ilog2() as u64).saturating_mul(self.size as u64)
+    }
+
+    /// Smallest size that gives at least `min_bits` bits of entropy, counting whole bits per
+    /// character
+    fn size_for_entropy(min_bits: u32) -> Option<usize> {
+        let per_char = N.ilog2();
+        if per_char == 0 {
+            return if min_bits == 0 { Some(0) } else { None };
+        }
+        usize::try_from(min_bits.div_ceil(per_char)).ok()
+    }
+
+    /// Increase the size if needed, so that ids have at least `min_bits` bits of entropy
+    ///
+    /// This guards against accidentally using tiny ids, for example for security tokens. The
+    /// size is never decreased. Each character is counted as `floor(log2(N))` bits, so for
+    /// alphabets whose length isn't a power of two, the size may be slightly larger than
+    /// strictly necessary. For reference, the default generator has 126 bits of entropy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_bits` is not zero, and the alphabet only has a single character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let mut gen = Generator::with_size(2).min_entropy(128);
+    /// assert_eq!(gen.gen().len(), 22);
+    ///
+    /// let mut gen = Generator::with_alphabet(&HEX).size(40).min_entropy(128);
+    /// assert_eq!(gen.gen().len(), 40);
+    /// ```
+    #[track_caller]
+    pub fn min_entropy(self, min_bits: u32) -> Self {
+        let min_size =
+            Self::size_for_entropy(min_bits).expect("a single character alphabet has no entropy");
+        let size = self.size.max(min_size);
+        Self { size, ..self }
+    }
+
+    /// Check that ids have at least `min_bits` bits of entropy
+    ///
+    /// This is like [`Generator::min_entropy`], but returns an error instead of increasing the
+    /// size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InsufficientEntropy`] if the size is too small.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{ConfigError, Generator};
+    ///
+    /// assert!(Generator::default().try_min_entropy(126).is_ok());
+    /// assert!(matches!(
+    ///     Generator::with_size(2).try_min_entropy(128),
+    ///     Err(ConfigError::InsufficientEntropy { bits: 12, required: 128 })
+    /// ));
+    /// ```
+    pub fn try_min_entropy(self, min_bits: u32) -> Result<Self, ConfigError> {
+        let bits = self.whole_entropy_bits();
+        if bits < u64::from(min_bits) {
+            return Err(ConfigError::InsufficientEntropy {
+                bits,
+                required: min_bits,
+            });
+        }
+        Ok(self)
+    }
+}
+
+/// Error for an invalid [`Config`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// The size is zero, so generated ids would be empty
+    ZeroSize,
+    /// The configured alphabet has a different length than the generator requires
+    AlphabetSize {
+        /// The length the generator requires
+        expected: usize,
+        /// The length of the configured alphabet
+        actual: usize,
+    },
+    /// The size is too large for an id to fit in memory
+    TooLarge,
+    /// Ids would have less entropy than required
+    InsufficientEntropy {
+        /// The number of bits of entropy in each id
+        bits: u64,
+        /// The minimum number of bits required
+        required: u32,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::ZeroSize => f.write_str("id size must be greater than zero"),
+            ConfigError::AlphabetSize { expected, actual } => write!(
+                f,
+                "alphabet has {actual} characters, but {expected} are required"
+            ),
+            ConfigError::TooLarge => f.write_str("id size is too large"),
+            ConfigError::InsufficientEntropy { bits, required } => write!(
+                f,
+                "ids have {bits} bits of entropy, but at least {required} are required"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConfigError {}
@@ -0,0 +1,173 @@
+//! Ids that combine a counter with random characters.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt;
+
+use rand::Rng;
+
+use crate::alphabet::NUMERIC;
+use crate::{Alphabet, Generator};
+
+/// A generator for ids made of a counter followed by random characters
+///
+/// Each id starts with the value of a counter, encoded with the generator's alphabet as a
+/// fixed width base `N` number, padded with the first character of the alphabet. It is
+/// followed by the generator's usual random characters. The counter is incremented for each
+/// id.
+///
+/// Because the counter never repeats, ids from the same `CounterGenerator` are always unique,
+/// without having to check for collisions. They are also ordered by when they were created,
+/// if compared character by character in alphabet order. For an alphabet in ascending order,
+/// such as [`HEX`](crate::alphabet::HEX), that is the same as normal string ordering.
+///
+/// The counter is only local to this generator, so ids from different generators, or
+/// different processes, can collide, and should have a large enough random part to make
+/// that unlikely.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{CounterGenerator, CounterOverflow, Generator, alphabet::HEX};
+///
+/// let mut gen = CounterGenerator::new(Generator::with_alphabet(&HEX).size(8), 2);
+/// let mut prev = gen.gen().unwrap();
+/// assert!(prev.starts_with("00"));
+/// assert_eq!(prev.len(), 10);
+/// for _ in 1..256 {
+///     let id = gen.gen().unwrap();
+///     assert!(id[..2] > prev[..2]);
+///     prev = id;
+/// }
+/// assert!(prev.starts_with("ff"));
+/// assert_eq!(gen.gen(), Err(CounterOverflow));
+/// ```
+pub struct CounterGenerator<'a, R, const N: usize = 64> {
+    generator: Generator<'a, R, N>,
+    counter: u128,
+    width: usize,
+    exhausted: bool,
+}
+
+impl<'a, R: Rng, const N: usize> CounterGenerator<'a, R, N> {
+    /// Create a new generator with a counter of `width` characters, starting at zero
+    pub fn new(generator: Generator<'a, R, N>, width: usize) -> Self {
+        Self::starting_at(generator, width, 0)
+    }
+
+    /// Create a new generator with a counter of `width` characters, starting at `start`
+    ///
+    /// This can be used to continue from a previously saved counter.
+    pub fn starting_at(generator: Generator<'a, R, N>, width: usize, start: u128) -> Self {
+        let exhausted = Alphabet::<N>::fixed_width_capacity(width).is_some_and(|cap| start >= cap);
+        CounterGenerator {
+            generator,
+            counter: start,
+            width,
+            exhausted,
+        }
+    }
+
+    /// The value of the counter that will be used for the next id
+    pub fn counter(&self) -> u128 {
+        self.counter
+    }
+
+    /// Generate a new id
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CounterOverflow`] if every value of the counter that fits in the configured
+    /// width has already been used.
+    pub fn gen(&mut self) -> Result<String, CounterOverflow> {
+        if self.exhausted {
+            return Err(CounterOverflow);
+        }
+        let alphabet = self.generator.alphabet;
+        let mut res = String::with_capacity(
+            self.width
+                .saturating_mul(alphabet.max_char_len())
+                .saturating_add(self.generator.max_id_bytes()),
+        );
+        alphabet
+            .write_u128_fixed(self.counter, self.width, &mut res)
+            .unwrap();
+        self.generator.push_to(&mut res);
+
+        match self.counter.checked_add(1) {
+            Some(next)
+                if Alphabet::<N>::fixed_width_capacity(self.width).is_none_or(|cap| next < cap) =>
+            {
+                self.counter = next;
+            }
+            _ => self.exhausted = true,
+        }
+        Ok(res)
+    }
+
+    /// Consume the `CounterGenerator`, and return the wrapped generator
+    pub fn into_inner(self) -> Generator<'a, R, N> {
+        self.generator
+    }
+}
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Generate a numeric id made of `value`, padded with zeros to `width` digits, followed by
+    /// random digits
+    ///
+    /// The number of random digits is the generator's size. They are chosen from
+    /// [`NUMERIC`], rather than the generator's alphabet, so the id
+    /// only contains decimal digits. This is useful for human facing references, such as order
+    /// numbers, where a sequence number is combined with a random part that makes them harder
+    /// to guess.
+    ///
+    /// For generating many ids with an incrementing counter, see [`CounterGenerator`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CounterOverflow`] if `value` has more than `width` digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{CounterOverflow, Generator};
+    ///
+    /// let mut gen = Generator::with_size(3);
+    /// let id = gen.gen_fixed_width_number(4217, 7).unwrap();
+    /// assert_eq!(id.len(), 10);
+    /// assert!(id.starts_with("0004217"));
+    /// assert!(id.chars().all(|c| c.is_ascii_digit()));
+    ///
+    /// assert_eq!(gen.gen_fixed_width_number(9_999_999, 7).unwrap().len(), 10);
+    /// assert_eq!(gen.gen_fixed_width_number(10_000_000, 7), Err(CounterOverflow));
+    /// ```
+    pub fn gen_fixed_width_number(
+        &mut self,
+        value: u128,
+        width: usize,
+    ) -> Result<String, CounterOverflow> {
+        if Alphabet::<10>::fixed_width_capacity(width).is_some_and(|cap| value >= cap) {
+            return Err(CounterOverflow);
+        }
+        let mut res = String::with_capacity(width.saturating_add(self.size));
+        NUMERIC.write_u128_fixed(value, width, &mut res).unwrap();
+        Generator::new(self.size, &NUMERIC, &mut self.random)
+            .sampling(self.sampling)
+            .push_to(&mut res);
+        Ok(res)
+    }
+}
+
+/// Error returned when the counter of a [`CounterGenerator`] has run out of values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CounterOverflow;
+
+impl fmt::Display for CounterOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("id counter doesn't fit in the configured width")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CounterOverflow {}
@@ -0,0 +1,105 @@
+//! Avoiding collisions with recently generated ids.
+#![cfg(feature = "dedup")]
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::Generator;
+
+/// A generator that never repeats any of its most recent ids
+///
+/// Remembers the last `capacity` ids it generated, and if a new id is the same as one of them,
+/// generates another one instead. Older ids are forgotten, so memory use stays bounded for
+/// long running processes. This is cheaper than keeping every id in a set, if you only need
+/// ids to be unique over a recent window, for example while they are still in use.
+///
+/// When the window is full, the least recently used id is forgotten. An id can't be generated
+/// again while it is remembered, so that is always the oldest one, and the window is kept in
+/// generation order. Each remembered id is only stored once, shared between that queue and
+/// the set used to look it up.
+///
+/// With a reasonable id size collisions are extremely unlikely anyway, so this is mostly a
+/// safeguard for short ids.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{DedupGenerator, Generator, alphabet::HEX};
+///
+/// let mut gen = DedupGenerator::new(Generator::with_alphabet(&HEX).size(2), 100);
+/// let ids: Vec<String> = (0..100).map(|_| gen.gen()).collect();
+/// for (i, id) in ids.iter().enumerate() {
+///     assert!(!ids[i + 1..].contains(id));
+/// }
+/// ```
+pub struct DedupGenerator<'a, R, const N: usize = 64> {
+    generator: Generator<'a, R, N>,
+    recent: VecDeque<Arc<str>>,
+    seen: HashSet<Arc<str>>,
+    capacity: usize,
+}
+
+impl<'a, R: Rng, const N: usize> DedupGenerator<'a, R, N> {
+    /// Create a new generator that remembers the last `capacity` ids
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is at least the number of distinct ids `generator` can produce,
+    /// since it would eventually be impossible to generate a new id.
+    #[track_caller]
+    pub fn new(generator: Generator<'a, R, N>, capacity: usize) -> Self {
+        assert!(
+            (capacity as u128) < generator.id_space_u128(),
+            "dedup capacity must be less than the number of possible ids"
+        );
+        DedupGenerator {
+            generator,
+            recent: VecDeque::with_capacity(capacity),
+            seen: HashSet::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Number of recent ids that are remembered
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns true if `id` is one of the remembered ids
+    pub fn contains(&self, id: &str) -> bool {
+        self.seen.contains(id)
+    }
+
+    /// Generate an id that is different from all of the remembered ids
+    pub fn gen(&mut self) -> String {
+        let id = loop {
+            let id = self.generator.gen();
+            if !self.seen.contains(id.as_str()) {
+                break id;
+            }
+        };
+        if self.capacity > 0 {
+            if self.recent.len() == self.capacity {
+                let oldest = self.recent.pop_front().unwrap();
+                self.seen.remove(&oldest);
+            }
+            let shared: Arc<str> = Arc::from(id.as_str());
+            self.recent.push_back(Arc::clone(&shared));
+            self.seen.insert(shared);
+        }
+        id
+    }
+
+    /// Forget all of the remembered ids
+    pub fn clear(&mut self) {
+        self.recent.clear();
+        self.seen.clear();
+    }
+
+    /// Consume the `DedupGenerator`, and return the wrapped generator
+    pub fn into_inner(self) -> Generator<'a, R, N> {
+        self.generator
+    }
+}
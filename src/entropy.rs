@@ -0,0 +1,173 @@
+//! Helpers for choosing an id size based on the probability of collisions.
+//!
+//! These need floating point functions that aren't in `core`, so they require either the
+//! `std` feature or the `libm` feature.
+#![cfg(any(feature = "std", feature = "libm"))]
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+
+use rand::Rng;
+
+#[cfg(not(feature = "std"))]
+use self::math::F64Ext;
+use crate::Generator;
+
+/// Number of bits of entropy in an id of `size` characters from an alphabet of `alphabet_len`
+/// characters
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(randoid::entropy_bits(21, 64), 126.0);
+/// assert_eq!(randoid::entropy_bits(8, 16), 32.0);
+/// ```
+pub fn entropy_bits(size: usize, alphabet_len: usize) -> f64 {
+    size as f64 * (alphabet_len as f64).log2()
+}
+
+/// Description of the strength of a generated id
+///
+/// Returned by [`Generator::gen_reported`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntropyInfo {
+    /// Number of bits of entropy in the id, see [`entropy_bits`]
+    pub bits: f64,
+    /// Number of characters in the alphabet the id was generated from
+    pub alphabet_len: usize,
+    /// Number of characters in the id
+    pub length: usize,
+}
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Generate a new random id, along with a description of its entropy
+    ///
+    /// This is useful for logging or auditing the strength of generated ids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::BASE58};
+    ///
+    /// let (id, info) = Generator::with_alphabet(&BASE58).size(22).gen_reported();
+    /// assert_eq!(id.chars().count(), info.length);
+    /// assert_eq!(info.length, 22);
+    /// assert_eq!(info.alphabet_len, 58);
+    /// assert_eq!(info.bits, 22.0 * 58f64.log2());
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_reported(&mut self) -> (String, EntropyInfo) {
+        let info = EntropyInfo {
+            bits: entropy_bits(self.size, N),
+            alphabet_len: N,
+            length: self.size,
+        };
+        (self.gen(), info)
+    }
+}
+
+/// Approximate probability that at least two of `count` ids are the same
+///
+/// The ids are `size` characters long, and use an alphabet of `alphabet_len` characters.
+/// This uses the standard approximation for the birthday problem, `1 - exp(-k(k-1) / 2M)`,
+/// where `k` is `count` and `M` is the number of possible ids.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::collision_probability;
+///
+/// // The default settings are very unlikely to collide
+/// assert!(collision_probability(1_000_000_000, 21, 64) < 1e-18);
+/// // But two character hex ids collide quickly
+/// assert!(collision_probability(100, 2, 16) > 0.99);
+/// ```
+pub fn collision_probability(count: u64, size: usize, alphabet_len: usize) -> f64 {
+    let pairs = pair_count(count);
+    let space = (alphabet_len as f64).powf(size as f64);
+    -(-pairs / space).exp_m1()
+}
+
+/// The minimum id size that keeps the probability of a collision at or below `probability`
+///
+/// Calculates how many characters from an alphabet of `alphabet_len` characters are needed
+/// so that the chance of any two of `expected_ids` ids being the same is at most
+/// `probability`. The result is always at least 1.
+///
+/// # Panics
+///
+/// Panics if `probability` isn't greater than 0, or `alphabet_len` is less than 2.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{size_for_collision_probability, collision_probability};
+///
+/// let size = size_for_collision_probability(1_000_000_000, 1e-9, 64);
+/// assert_eq!(size, 15);
+/// assert!(collision_probability(1_000_000_000, size, 64) <= 1e-9);
+/// assert!(collision_probability(1_000_000_000, size - 1, 64) > 1e-9);
+/// ```
+#[track_caller]
+pub fn size_for_collision_probability(
+    expected_ids: u64,
+    probability: f64,
+    alphabet_len: usize,
+) -> usize {
+    assert!(probability > 0.0, "probability must be greater than zero");
+    assert!(
+        alphabet_len >= 2,
+        "alphabet must have at least 2 characters"
+    );
+    if probability >= 1.0 {
+        return 1;
+    }
+    // Solve `p = 1 - exp(-pairs / space)` for space
+    let space_bits = pair_count(expected_ids).log2() - (-(-probability).ln_1p()).log2();
+    let size = (space_bits / (alphabet_len as f64).log2()).ceil();
+    if size < 1.0 {
+        1
+    } else {
+        size as usize
+    }
+}
+
+/// Number of distinct pairs among `count` items, as a float
+fn pair_count(count: u64) -> f64 {
+    let count = count as f64;
+    count * (count - 1.0) / 2.0
+}
+
+/// Floating point functions from `libm`, for when `std` isn't available
+#[cfg(not(feature = "std"))]
+mod math {
+    pub(super) trait F64Ext {
+        fn log2(self) -> Self;
+        fn powf(self, n: Self) -> Self;
+        fn exp_m1(self) -> Self;
+        fn ln_1p(self) -> Self;
+        fn ceil(self) -> Self;
+    }
+
+    impl F64Ext for f64 {
+        fn log2(self) -> f64 {
+            libm::log2(self)
+        }
+
+        fn powf(self, n: f64) -> f64 {
+            libm::pow(self, n)
+        }
+
+        fn exp_m1(self) -> f64 {
+            libm::expm1(self)
+        }
+
+        fn ln_1p(self) -> f64 {
+            libm::log1p(self)
+        }
+
+        fn ceil(self) -> f64 {
+            libm::ceil(self)
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! An owned id type.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+
+use rand::Rng;
+
+use crate::Generator;
+
+/// A generated id
+///
+/// This is a thin wrapper around a [`String`] that makes it clear in type signatures that a
+/// value is an id. It implements [`Borrow<str>`], so collections keyed by `Id` can be queried
+/// with a plain `&str`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use randoid::{Generator, Id};
+///
+/// let mut gen = Generator::with_size(8);
+/// let id = gen.gen_id();
+/// let key = id.to_string();
+///
+/// let mut users = HashMap::new();
+/// users.insert(id, "alice");
+/// assert_eq!(users.get(key.as_str()), Some(&"alice"));
+/// assert_eq!(users.get("not an id"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(String);
+
+impl Id {
+    /// Get the id as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Convert the id into the underlying [`String`]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for Id {
+    fn from(id: String) -> Self {
+        Id(id)
+    }
+}
+
+impl From<Id> for String {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+impl Deref for Id {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Id {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Id {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Id {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Id {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.0)
+    }
+}
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Generate a new random id as an [`Id`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let id = Generator::with_size(12).gen_id();
+    /// assert_eq!(id.len(), 12);
+    /// ```
+    pub fn gen_id(&mut self) -> Id {
+        Id(self.gen())
+    }
+}
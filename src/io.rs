@@ -0,0 +1,203 @@
+//! Reading and writing ids with [`std::io`].
+#![cfg(feature = "std")]
+
+use std::io;
+
+use rand::Rng;
+
+use crate::Generator;
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Write `count` new ids to `out`, separated by `sep`
+    ///
+    /// The separator is only written between ids, not after the last one.
+    ///
+    /// Each id is written to a single scratch buffer that is reused for all of them, so this
+    /// uses the same amount of memory no matter how many ids are written. Consider wrapping
+    /// `out` in a [`BufWriter`](std::io::BufWriter) if it is a file or socket.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `out`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut out = Vec::new();
+    /// randoid::Generator::default().write_batch(&mut out, 1000, b'\n').unwrap();
+    ///
+    /// assert_eq!(out.iter().filter(|&&b| b == b'\n').count(), 999);
+    /// let out = String::from_utf8(out).unwrap();
+    /// assert!(out.lines().all(|id| id.len() == 21));
+    /// ```
+    pub fn write_batch<W: io::Write + ?Sized>(
+        &mut self,
+        out: &mut W,
+        count: usize,
+        sep: u8,
+    ) -> io::Result<()> {
+        self.write_batch_inner(out, count, sep, None)
+    }
+
+    /// Write `count` new ids to `out`, separated by `sep`, and flush `out` after every
+    /// `flush_every` ids
+    ///
+    /// This is like [`Generator::write_batch`], but also calls [`flush`](io::Write::flush),
+    /// after every `flush_every` ids, and after the last id. When streaming a lot of ids to a
+    /// [`BufWriter`](std::io::BufWriter), this makes them available to the reader in chunks,
+    /// instead of whenever the buffer happens to fill up, and reports errors from the
+    /// underlying writer promptly. If `flush_every` is zero, `out` is only flushed at the end.
+    /// It is flushed at the end even if `count` is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `out`, including from flushing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{BufWriter, Write};
+    ///
+    /// let mut out = BufWriter::new(Vec::new());
+    /// randoid::Generator::with_size(8).write_batch_flushing(&mut out, 10, b'\n', 4).unwrap();
+    /// // Everything has been flushed, so the buffer is empty
+    /// assert!(out.buffer().is_empty());
+    /// assert_eq!(out.get_ref().len(), 10 * 9 - 1);
+    /// ```
+    pub fn write_batch_flushing<W: io::Write + ?Sized>(
+        &mut self,
+        out: &mut W,
+        count: usize,
+        sep: u8,
+        flush_every: usize,
+    ) -> io::Result<()> {
+        self.write_batch_inner(out, count, sep, Some(flush_every))
+    }
+
+    /// Shared implementation of [`Generator::write_batch`] and
+    /// [`Generator::write_batch_flushing`]
+    ///
+    /// If `flush_every` is `None`, `out` is never flushed.
+    fn write_batch_inner<W: io::Write + ?Sized>(
+        &mut self,
+        out: &mut W,
+        count: usize,
+        sep: u8,
+        flush_every: Option<usize>,
+    ) -> io::Result<()> {
+        let mut scratch = String::with_capacity(self.max_id_bytes());
+        for i in 0..count {
+            if i > 0 {
+                out.write_all(&[sep])?;
+            }
+            scratch.clear();
+            self.write_to(&mut scratch).unwrap();
+            out.write_all(scratch.as_bytes())?;
+            // The last id is flushed below, whether or not it is at the end of an interval
+            let written = i + 1;
+            if flush_every.is_some_and(|k| k > 0 && written % k == 0 && written < count) {
+                out.flush()?;
+            }
+        }
+        if flush_every.is_some() {
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Turn the generator into an endless [`Read`](io::Read) stream of ids
+    ///
+    /// If `sep` is `Some`, it is written after each id, for example `Some(b'\n')` produces
+    /// one id per line. Otherwise the ids are concatenated into a continuous stream of random
+    /// characters.
+    ///
+    /// See [`IdReader`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let mut out = String::new();
+    /// Generator::with_alphabet(&HEX)
+    ///     .size(8)
+    ///     .into_reader(Some(b'\n'))
+    ///     .take(9 * 100)
+    ///     .read_to_string(&mut out)
+    ///     .unwrap();
+    /// assert_eq!(out.lines().count(), 100);
+    /// assert!(out.lines().all(|id| id.len() == 8 && HEX.validate(id)));
+    /// ```
+    pub fn into_reader(self, sep: Option<u8>) -> IdReader<'a, R, N> {
+        IdReader {
+            generator: self,
+            sep,
+            pending: Vec::new(),
+            pos: 0,
+        }
+    }
+}
+
+/// An endless stream of ids, which implements [`Read`](io::Read)
+///
+/// Created by [`Generator::into_reader`]. This allows using generic IO functions, such as
+/// [`io::copy`], to write ids to a file, a pipe, or another tool.
+///
+/// The stream never ends, so make sure to limit how much is read, for example with
+/// [`Read::take`](io::Read::take). The only exception is a generator with a size of zero and no
+/// separator, which would never produce any data, so it is empty instead.
+///
+/// Reads can end in the middle of an id, or in the middle of a multi-byte character. The rest
+/// is returned by the next read.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Read};
+/// use randoid::Generator;
+///
+/// let mut out = Vec::new();
+/// let copied = io::copy(&mut Generator::default().into_reader(None).take(1000), &mut out).unwrap();
+/// assert_eq!(copied, 1000);
+/// assert!(randoid::alphabet::DEFAULT.validate(std::str::from_utf8(&out).unwrap()));
+/// ```
+pub struct IdReader<'a, R, const N: usize = 64> {
+    generator: Generator<'a, R, N>,
+    sep: Option<u8>,
+    /// The current id, and its separator
+    pending: Vec<u8>,
+    /// How much of `pending` has already been read
+    pos: usize,
+}
+
+impl<'a, R, const N: usize> IdReader<'a, R, N> {
+    /// Consume the `IdReader`, and return the wrapped generator
+    ///
+    /// Any part of the current id that hasn't been read yet is discarded.
+    pub fn into_inner(self) -> Generator<'a, R, N> {
+        self.generator
+    }
+}
+
+impl<R: Rng, const N: usize> io::Read for IdReader<'_, R, N> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+        while written < buf.len() {
+            if self.pos == self.pending.len() {
+                if self.generator.size == 0 && self.sep.is_none() {
+                    break;
+                }
+                self.pending.clear();
+                self.pos = 0;
+                self.generator.extend_bytes(&mut self.pending);
+                self.pending.extend(self.sep);
+            }
+            let n = (self.pending.len() - self.pos).min(buf.len() - written);
+            buf[written..written + n].copy_from_slice(&self.pending[self.pos..self.pos + n]);
+            self.pos += n;
+            written += n;
+        }
+        Ok(written)
+    }
+}
@@ -0,0 +1,83 @@
+//! Deterministic ids derived from a key.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use rand::RngCore;
+
+use crate::{Alphabet, Generator};
+
+/// Generate an id that is derived from `key`
+///
+/// The same key, size, and alphabet always produce the same id, which is useful for
+/// assigning ids idempotently, for example to content or to records imported from another
+/// system.
+///
+/// The key is hashed with 64-bit [FNV-1a], and the hash is used as the seed of a
+/// [SplitMix64] generator, which provides the random data for the id. Both are part of
+/// the stable output of this function, and won't change without a breaking release.
+///
+/// **This is not suitable for secrets.** Anyone who knows the key can compute the id,
+/// and the seed only has 64 bits, so different keys are more likely to collide than random
+/// ids with more than 64 bits of entropy.
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{gen_from_key, alphabet::DEFAULT};
+///
+/// let id = gen_from_key(b"user@example.com", 21, &DEFAULT);
+/// assert_eq!(id, gen_from_key(b"user@example.com", 21, &DEFAULT));
+/// assert_ne!(id, gen_from_key(b"other@example.com", 21, &DEFAULT));
+/// assert_eq!(id.len(), 21);
+/// ```
+pub fn gen_from_key<const N: usize>(key: &[u8], size: usize, alphabet: &Alphabet<N>) -> String {
+    Generator::new(size, alphabet, SplitMix64(fnv1a(key))).gen()
+}
+
+/// 64-bit FNV-1a hash
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(PRIME)
+    })
+}
+
+/// The increment of the SplitMix64 state, derived from the golden ratio
+pub(crate) const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// The finalizer of SplitMix64, which thoroughly mixes the bits of `z`
+pub(crate) fn splitmix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// The SplitMix64 pseudo random number generator
+///
+/// This is implemented here, rather than using an rng from `rand`, so that the output is
+/// stable regardless of the version of `rand`.
+struct SplitMix64(u64);
+
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(GOLDEN_GAMMA);
+        splitmix64(self.0)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
@@ -3,40 +3,147 @@
 #![deny(missing_docs)]
 
 use core::cell::RefCell;
+use core::convert::Infallible;
 use core::fmt::{self, Write};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 pub mod alphabet;
+mod ascii;
+mod config;
+mod counter;
+mod dedup;
+mod entropy;
+mod id;
+mod io;
+mod keyed;
+mod obfuscate;
+mod parts;
+mod prepared;
+mod reseeding;
+pub mod rngs;
 mod std_rand;
+mod store;
+mod tag;
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod testing;
+mod timestamp;
+mod uuid;
 
-pub use alphabet::{Alphabet, HexAlphabet};
+pub use alphabet::{Alphabet, HexAlphabet, InvalidAlphabet, NonAsciiAlphabet};
+pub use ascii::{AsciiAlphabet, AsciiGenerator};
+pub use config::{Config, ConfigError};
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use counter::{CounterGenerator, CounterOverflow};
+#[cfg(feature = "dedup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dedup")))]
+pub use dedup::DedupGenerator;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use entropy::{
+    collision_probability, entropy_bits, size_for_collision_probability, EntropyInfo,
+};
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use id::Id;
+#[cfg(feature = "std")]
+pub use io::IdReader;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use keyed::gen_from_key;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use obfuscate::Obfuscator;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use parts::{IdParts, PartsIter};
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use prepared::PreparedId;
 use rand::Rng;
 #[cfg(feature = "std-rand")]
+pub use reseeding::ReseedingGenerator;
+#[cfg(feature = "std-rand")]
 pub use std_rand::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use store::{IdStore, NotEnoughUnique};
+#[cfg(feature = "std")]
+pub use timestamp::parse_timestamp;
 
 /// Size of the buffer to store batched random data in.
 ///
-/// This should be big enough to fit the step size of random data
-/// for up to 40 characters in the general case, and 64 if the alphabet has a
-/// size that is a power of 2.
+/// This is big enough to fit the step size of random data for 64 characters if the
+/// alphabet has a size that is a power of 2, and at least 32 characters otherwise.
 const BUFFER_SIZE: usize = 64;
 
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Scratch space for random data, used by [`Generator::gen_tls`]
+    static SCRATCH: RefCell<[u8; BUFFER_SIZE]> = const { RefCell::new([0; BUFFER_SIZE]) };
+}
+
 /// Default length of a generated id (21)
 pub const DEFAULT_SIZE: usize = 21;
 
+/// Default limit on the length of an id in bytes for [`Generator::try_gen`] (64 KiB)
+pub const DEFAULT_MAX_ID_BYTES: usize = 64 * 1024;
+
+/// Generator for random ids
 ///
+/// Combines an alphabet, a size, and a source of random data.
 #[derive(Clone)]
 pub struct Generator<'a, R, const N: usize = 64> {
     alphabet: &'a Alphabet<N>,
     random: R,
     size: usize,
+    sampling: Sampling,
+    rejection_bits: Option<u32>,
 }
 
-impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+/// The rng is not included in the debug output, since its state could be used to predict
+/// future ids.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::HEX};
+/// # use rand::SeedableRng;
+///
+/// let rand = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0x04040404);
+/// let gen = Generator::new(8, &HEX, rand);
+/// assert_eq!(
+///     format!("{:?}", gen),
+///     "Generator { size: 8, alphabet_len: 16, rng: <redacted> }",
+/// );
+/// ```
+impl<R, const N: usize> fmt::Debug for Generator<'_, R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Generator")
+            .field("size", &self.size)
+            .field("alphabet_len", &N)
+            .field("rng", &format_args!("<redacted>"))
+            .finish()
+    }
+}
+
+/// How a [`Generator`] turns random data into characters of the alphabet
+///
+/// Both strategies choose each character uniformly, but they use the random data differently,
+/// so they produce different ids from the same rng.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Sampling {
+    /// Mask random bytes to the smallest power of two that is at least the alphabet size,
+    /// and reject values that are out of range
+    ///
+    /// If the alphabet size is a power of two, this uses exactly one byte per character.
+    #[default]
+    Bytes,
+    /// Use [`rand::distr::Uniform`] to sample each index
+    ///
+    /// This uses `rand`'s own unbiased sampling, at the cost of drawing at least 32 bits of
+    /// random data per character.
+    Uniform,
+}
+
+impl<'a, R, const N: usize> Generator<'a, R, N> {
     /// Create a new, fully specified id generator
     ///
     /// Create a new generator that genartes ids composed of `size` characters chosen at random
@@ -58,9 +165,32 @@ impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
             size,
             alphabet,
             random,
+            sampling: Sampling::Bytes,
+            rejection_bits: None,
         }
     }
 
+    /// Create a new generator using a specific alphabet and source of random data
+    ///
+    /// And using the default size. This is like [`Generator::with_alphabet`], but doesn't
+    /// need the `std-rand` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    /// # use rand::SeedableRng;
+    ///
+    /// let rand = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0x04040404);
+    /// let mut gen = Generator::with_alphabet_and_random(&HEX, rand);
+    /// let id = gen.gen();
+    /// assert_eq!(id.len(), 21);
+    /// assert!(id.starts_with("905c2761"));
+    /// ```
+    pub fn with_alphabet_and_random(alphabet: &'a Alphabet<N>, random: R) -> Self {
+        Self::new(DEFAULT_SIZE, alphabet, random)
+    }
+
     /// Update the size of an existing generator
     ///
     /// # Example
@@ -75,6 +205,94 @@ impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
         Self { size, ..self }
     }
 
+    /// Update how random data is turned into characters
+    ///
+    /// See [`Sampling`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use randoid::{Generator, Sampling, alphabet::BASE58};
+    ///
+    /// let id = Generator::with_alphabet(&BASE58).sampling(Sampling::Uniform).gen();
+    /// assert!(BASE58.validate(&id));
+    /// ```
+    pub fn sampling(self, sampling: Sampling) -> Self {
+        Self { sampling, ..self }
+    }
+
+    /// Update the number of bits of random data used for each character, with
+    /// [`Sampling::Bytes`]
+    ///
+    /// Each byte of random data is masked to the lowest `bits` bits, and the result is used as
+    /// an index into the alphabet, modulo its size. To keep the distribution uniform, values
+    /// at or above the largest multiple of the alphabet size that fits in `bits` bits are
+    /// rejected, and another byte is used instead.
+    ///
+    /// By default, this uses the fewest bits that can represent every index into the alphabet,
+    /// so the modulus never changes the index. Using more bits makes fewer values rejected, and
+    /// so less random data is needed, for alphabets whose size is just over a power of two.
+    /// For example, with an alphabet of 33 characters, the default of 6 bits rejects almost
+    /// half of the bytes, while 8 bits only rejects about 10%. The ids are different from
+    /// the ids generated with the default, given the same random data. Either way,
+    /// [`Generator::bytes_per_id`] is the least random data that an id can use.
+    ///
+    /// `bits` is clamped to be at least the default. Use [`Generator::rejection_mask`] to get
+    /// the resulting mask.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is greater than 8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use randoid::{Generator, Alphabet};
+    ///
+    /// // The 33 characters from `!` to `A`
+    /// let alphabet = Alphabet::<33>::from_range('!', 'A');
+    /// let gen = Generator::with_alphabet(&alphabet);
+    /// assert_eq!(gen.rejection_mask(), 0b11_1111);
+    /// assert_eq!(gen.bytes_per_id(), 41);
+    ///
+    /// let mut gen = gen.rejection_bits(8);
+    /// assert_eq!(gen.rejection_mask(), 0xff);
+    /// assert_eq!(gen.bytes_per_id(), 24);
+    /// assert!(alphabet.validate(&gen.gen()));
+    /// ```
+    #[track_caller]
+    pub fn rejection_bits(self, bits: u32) -> Self {
+        assert!(
+            bits <= 8,
+            "can't use more than 8 bits of random data per character"
+        );
+        Self {
+            rejection_bits: Some(bits),
+            ..self
+        }
+    }
+
+    /// The mask applied to each byte of random data, with [`Sampling::Bytes`]
+    ///
+    /// See [`Generator::rejection_bits`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::{BASE58, HEX}};
+    ///
+    /// assert_eq!(Generator::with_alphabet(&HEX).rejection_mask(), 0xf);
+    /// assert_eq!(Generator::with_alphabet(&BASE58).rejection_mask(), 0x3f);
+    /// ```
+    pub fn rejection_mask(&self) -> usize {
+        // The smallest mask that covers every index into the alphabet.
+        let min = N.next_power_of_two() - 1;
+        match self.rejection_bits {
+            Some(bits) => min.max((1 << bits) - 1),
+            None => min,
+        }
+    }
+
     /// Update the alphabet of an existing generator
     ///
     /// # Example
@@ -90,7 +308,200 @@ impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
             alphabet,
             size: self.size,
             random: self.random,
+            sampling: self.sampling,
+            rejection_bits: self.rejection_bits,
+        }
+    }
+
+    /// Replace the alphabet with another alphabet of the same length, in place
+    ///
+    /// Unlike [`Generator::alphabet`], this doesn't consume the generator, but the new alphabet
+    /// must have the same number of characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::{HEX, HEX_UPPER}};
+    ///
+    /// let mut gen = Generator::with_alphabet(&HEX).size(32);
+    /// let lower = gen.gen();
+    /// gen.set_alphabet(&HEX_UPPER);
+    /// let upper = gen.gen();
+    /// assert!(!lower.contains(|c: char| c.is_ascii_uppercase()));
+    /// assert!(!upper.contains(|c: char| c.is_ascii_lowercase()));
+    /// ```
+    pub fn set_alphabet(&mut self, alphabet: &'a Alphabet<N>) {
+        self.alphabet = alphabet;
+    }
+
+    /// The alphabet the generator currently uses
+    ///
+    /// This is a getter, unlike [`Generator::alphabet`], which replaces the alphabet. It is
+    /// useful for checking ids against the same configuration, for example with
+    /// [`Alphabet::validate`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::BASE58};
+    ///
+    /// let gen = Generator::with_alphabet(&BASE58).size(12);
+    /// assert_eq!(gen.current_alphabet().len(), 58);
+    /// assert_eq!(gen.current_size(), 12);
+    ///
+    /// let gen = Generator::default();
+    /// assert_eq!(gen.current_alphabet().as_chars(), randoid::alphabet::DEFAULT.as_chars());
+    /// assert_eq!(gen.current_size(), randoid::DEFAULT_SIZE);
+    /// ```
+    pub fn current_alphabet(&self) -> &'a Alphabet<N> {
+        self.alphabet
+    }
+
+    /// The number of characters in each id the generator creates
+    ///
+    /// This is a getter, unlike [`Generator::size`], which replaces the size.
+    pub fn current_size(&self) -> usize {
+        self.size
+    }
+
+    /// Consume the generator, and return its source of random data
+    pub fn into_random(self) -> R {
+        self.random
+    }
+}
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// The maximum number of bytes an id can take when encoded as UTF-8
+    ///
+    /// This is useful for allocating a buffer big enough to hold one or more ids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, Alphabet};
+    ///
+    /// let gen = Generator::with_size(16);
+    /// assert_eq!(gen.max_id_bytes(), 16);
+    /// assert_eq!(gen.min_id_bytes(), 16);
+    ///
+    /// let mixed = Alphabet::new(['a', 'é', '€', '🦀']);
+    /// let gen = Generator::with_alphabet(&mixed).size(10);
+    /// assert_eq!(gen.max_id_bytes(), 40);
+    /// assert_eq!(gen.min_id_bytes(), 10);
+    /// ```
+    pub fn max_id_bytes(&self) -> usize {
+        self.size.saturating_mul(self.alphabet.max_char_len())
+    }
+
+    /// The minimum number of bytes an id can take when encoded as UTF-8
+    ///
+    /// See [`Generator::max_id_bytes`].
+    pub fn min_id_bytes(&self) -> usize {
+        self.size.saturating_mul(self.alphabet.min_char_len())
+    }
+
+    /// The minimum number of random bytes used to generate one id
+    ///
+    /// With [`Sampling::Bytes`], each character uses one byte of random data. If the size of
+    /// the alphabet is a power of 2, this is exact. Otherwise, some random bytes can't be mapped
+    /// to a character and have to be discarded. Random data is drawn in batches that allow for
+    /// the expected number of discarded bytes, and another batch is drawn if more are
+    /// discarded, so the actual number is often higher.
+    ///
+    /// With [`Sampling::Uniform`], each character uses a `u32`, so this is four bytes per
+    /// character, although `rand` may occasionally draw more.
+    ///
+    /// This saturates at `usize::MAX`. It can be used to budget random data from a limited
+    /// source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    /// use rand::RngCore;
+    ///
+    /// /// Counts the bytes consumed from another rng
+    /// struct Counting<R>(R, usize);
+    ///
+    /// impl<R: RngCore> RngCore for Counting<R> {
+    ///     fn next_u32(&mut self) -> u32 { self.1 += 4; self.0.next_u32() }
+    ///     fn next_u64(&mut self) -> u64 { self.1 += 8; self.0.next_u64() }
+    ///     fn fill_bytes(&mut self, dest: &mut [u8]) {
+    ///         self.1 += dest.len();
+    ///         self.0.fill_bytes(dest)
+    ///     }
+    /// }
+    ///
+    /// let mut gen = Generator::with_random(Counting(rand::rng(), 0)).size(100);
+    /// assert_eq!(gen.bytes_per_id(), 100);
+    /// gen.gen();
+    /// assert_eq!(gen.into_random().1, 100);
+    /// ```
+    pub fn bytes_per_id(&self) -> usize {
+        match self.sampling {
+            Sampling::Bytes => {
+                // This mirrors the batches drawn by `for_each_masked_index`, when every batch
+                // has enough valid bytes. Only the last batch is smaller than the buffer.
+                let mask = self.rejection_mask();
+                let limit = (mask + 1) / N * N;
+                let full = self.size / BUFFER_SIZE * BUFFER_SIZE;
+                let last = ((self.size % BUFFER_SIZE) * (mask + 1))
+                    .div_ceil(limit)
+                    .min(BUFFER_SIZE);
+                full.saturating_add(last)
+            }
+            Sampling::Uniform => self.size.saturating_mul(4),
+        }
+    }
+
+    /// Number of distinct ids this generator can produce
+    ///
+    /// This is `N^size`, where `N` is the size of the alphabet. It can be very large, so it is
+    /// returned as a floating point number, which is infinite if it is larger than
+    /// [`f64::MAX`]. See [`id_space_u128`](Self::id_space_u128) for an exact count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// assert_eq!(Generator::with_random(rand::rng()).alphabet(&HEX).size(2).id_space(), 256.0);
+    /// assert_eq!(Generator::with_random(rand::rng()).id_space(), 2f64.powi(126));
+    /// ```
+    pub fn id_space(&self) -> f64 {
+        let mut base = N as f64;
+        let mut exp = self.size;
+        let mut result = 1.0;
+        while exp > 0 && result < f64::INFINITY {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
         }
+        result
+    }
+
+    /// Number of distinct ids this generator can produce, as an integer
+    ///
+    /// Like [`id_space`](Self::id_space), but returns an exact count, saturating at
+    /// [`u128::MAX`] if there are more possible ids than that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let gen = Generator::with_random(rand::rng()).alphabet(&HEX);
+    /// assert_eq!(gen.clone().size(2).id_space_u128(), 256);
+    /// assert_eq!(gen.clone().size(32).id_space_u128(), u128::MAX);
+    /// assert_eq!(gen.size(31).id_space_u128(), 1 << 124);
+    /// ```
+    pub fn id_space_u128(&self) -> u128 {
+        u32::try_from(self.size)
+            .ok()
+            .and_then(|size| (N as u128).checked_pow(size))
+            .unwrap_or(u128::MAX)
     }
 
     /// Generate a new id, and write the result to `out`
@@ -111,33 +522,325 @@ impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
     /// assert_eq!(ids.len(), 21 * 2 + 1);
     /// ```
     ///
+    /// Since [`fmt::Formatter`] implements [`fmt::Write`], this can also write an id directly
+    /// from a `Display` or `Debug` implementation. Formatting options, such as width, aren't
+    /// applied to the id, use [`Generator::fmt`] if you need those.
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// /// A new tracing span, with a random id
+    /// struct Span(&'static str);
+    ///
+    /// impl fmt::Display for Span {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{}#", self.0)?;
+    ///         Generator::with_alphabet(&HEX).size(8).write_to(f)
+    ///     }
+    /// }
+    ///
+    /// let span = Span("request").to_string();
+    /// assert_eq!(span.len(), 16);
+    /// assert!(span.starts_with("request#"));
+    /// assert!(HEX.validate(&span[8..]));
+    /// ```
+    ///
     /// # See Also
     /// - [`Generator::fmt`]
     /// - [`Generator::gen`]
     /// - [`Generator::gen_smartstring`]
     /// - [`Generator::fmt`]
-    pub fn write_to<W: Write>(&mut self, out: &mut W) -> fmt::Result {
-        if self.size == 0 {
+    pub fn write_to<W: Write + ?Sized>(&mut self, out: &mut W) -> fmt::Result {
+        self.write_n(self.size, out)
+    }
+
+    /// Write `count` new ids to `out`, separated by `sep`
+    ///
+    /// The separator is only written between ids, not after the last one, and nothing is
+    /// written if `count` is zero. Unlike [`Generator::write_batch`], this works with
+    /// [`fmt::Write`], so it doesn't need `std`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `out` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let mut gen = Generator::with_size(8);
+    /// let mut ids = String::with_capacity(3 * 9);
+    /// gen.write_n_to(&mut ids, 3, ',').unwrap();
+    /// assert_eq!(ids.len(), 26);
+    /// assert!(ids.split(',').all(|id| id.len() == 8));
+    /// assert_eq!(ids.split(',').count(), 3);
+    ///
+    /// let mut empty = String::new();
+    /// gen.write_n_to(&mut empty, 0, ',').unwrap();
+    /// assert!(empty.is_empty());
+    /// ```
+    pub fn write_n_to<W: Write + ?Sized>(
+        &mut self,
+        out: &mut W,
+        count: usize,
+        sep: char,
+    ) -> fmt::Result {
+        for i in 0..count {
+            if i > 0 {
+                out.write_char(sep)?;
+            }
+            self.write_n(self.size, out)?;
+        }
+        Ok(())
+    }
+
+    /// Write an id of `count` characters to `out`
+    #[inline]
+    fn write_n<W: Write + ?Sized>(&mut self, count: usize, out: &mut W) -> fmt::Result {
+        let alphabet = self.alphabet;
+        self.for_each_index(count, |idx| out.write_char(alphabet.chars[idx]))
+    }
+
+    /// Generate a new id, and write it to `out` as ASCII bytes
+    ///
+    /// Writes as many characters of the id as fit in `out`, which is the smaller of
+    /// `out.len()` and the configured size, and returns the number of bytes written.
+    /// Any remaining bytes in `out` are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonAsciiAlphabet`] if the alphabet contains any non-ASCII characters,
+    /// since those can't be represented as a single byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    /// # use rand::SeedableRng;
+    ///
+    /// let rand = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0x04040404);
+    /// let mut gen = Generator::new(8, &HEX, rand);
+    ///
+    /// // A buffer that is too small only gets as many bytes as fit
+    /// let mut small = [0u8; 4];
+    /// assert_eq!(gen.try_write_bytes(&mut small), Ok(4));
+    /// assert_eq!(&small, b"2761");
+    ///
+    /// // A buffer that is too big only gets `size` bytes
+    /// let mut big = [b'.'; 12];
+    /// assert_eq!(gen.try_write_bytes(&mut big), Ok(8));
+    /// assert!(big[..8].iter().all(u8::is_ascii_hexdigit));
+    /// assert_eq!(&big[8..], b"....");
+    /// ```
+    ///
+    /// ```
+    /// use randoid::{Generator, Alphabet, NonAsciiAlphabet};
+    ///
+    /// let alphabet = Alphabet::new(['a', 'b', 'c', 'é']);
+    /// let mut gen = Generator::with_alphabet(&alphabet);
+    /// assert_eq!(gen.try_write_bytes(&mut [0u8; 21]), Err(NonAsciiAlphabet));
+    /// ```
+    pub fn try_write_bytes(&mut self, out: &mut [u8]) -> Result<usize, NonAsciiAlphabet> {
+        if !self.alphabet.is_ascii() {
+            return Err(NonAsciiAlphabet);
+        }
+        Ok(self.write_ascii_bytes(out))
+    }
+
+    /// Write an id to `out` as bytes, without checking that the alphabet is ASCII
+    ///
+    /// If the alphabet isn't ASCII, the characters are truncated to a byte.
+    fn write_ascii_bytes(&mut self, out: &mut [u8]) -> usize {
+        let alphabet = self.alphabet;
+        let len = out.len().min(self.size);
+        let mut pos = 0;
+        self.for_each_index(len, |idx| {
+            // The alphabet is ASCII, so this cast doesn't truncate.
+            out[pos] = alphabet.chars[idx] as u8;
+            pos += 1;
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        len
+    }
+
+    /// Overwrite all of `dst` with random characters from the alphabet
+    ///
+    /// Every byte of `dst` is replaced, so the id has `dst.len()` characters, regardless of the
+    /// configured size. This is useful for filling in a placeholder in a larger string
+    /// without reallocating it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonAsciiAlphabet`] if the alphabet contains any non-ASCII characters,
+    /// since those can't be represented as a single byte. In that case `dst` is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let mut record = String::from("user:________:active");
+    /// Generator::with_alphabet(&HEX).fill_str(&mut record[5..13]).unwrap();
+    /// assert!(record.starts_with("user:"));
+    /// assert!(record.ends_with(":active"));
+    /// assert!(record[5..13].chars().all(|c| c.is_ascii_hexdigit()));
+    /// ```
+    pub fn fill_str(&mut self, dst: &mut str) -> Result<(), NonAsciiAlphabet> {
+        if !self.alphabet.is_ascii() {
+            return Err(NonAsciiAlphabet);
+        }
+        let alphabet = self.alphabet;
+        // SAFETY: Every byte is overwritten with an ASCII character before `dst` can be used
+        // again, and a string of ASCII characters is always valid UTF-8. If the rng panics
+        // part way through, `AsciiFill` overwrites the remaining bytes when it is dropped.
+        let mut fill = AsciiFill {
+            bytes: unsafe { dst.as_bytes_mut() },
+            pos: 0,
+        };
+        let len = fill.bytes.len();
+        self.for_each_index(len, |idx| {
+            // The alphabet is ASCII, so this cast doesn't truncate.
+            fill.bytes[fill.pos] = alphabet.chars[idx] as u8;
+            fill.pos += 1;
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        Ok(())
+    }
+
+    /// Iterate over random bytes from the generator's source of random data
+    ///
+    /// This allows using the same rng for other purposes, without creating a second one.
+    /// Bytes are drawn from the rng in batches, and consuming them advances the rng, so it
+    /// affects the ids generated afterwards. Bytes drawn for a batch but not consumed before
+    /// the iterator is dropped are discarded.
+    ///
+    /// The iterator never ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let mut gen = Generator::default();
+    /// let bytes: Vec<u8> = gen.bytes().take(100).collect();
+    /// assert_eq!(bytes.len(), 100);
+    /// assert!(bytes.iter().any(|&b| b != 0));
+    /// ```
+    pub fn bytes(&mut self) -> impl Iterator<Item = u8> + '_ {
+        let random = &mut self.random;
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let mut pos = BUFFER_SIZE;
+        core::iter::from_fn(move || {
+            if pos == BUFFER_SIZE {
+                random.fill(&mut buffer);
+                pos = 0;
+            }
+            let b = buffer[pos];
+            pos += 1;
+            Some(b)
+        })
+    }
+
+    /// Generate a single random index into the alphabet
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn random_index(&mut self) -> usize {
+        let mut res = 0;
+        self.for_each_index(1, |idx| {
+            res = idx;
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        res
+    }
+
+    /// Generate `count` random indices into the alphabet, and pass each of them to `f`
+    ///
+    /// Stops early if `f` returns an error.
+    #[inline]
+    fn for_each_index<E>(
+        &mut self,
+        count: usize,
+        f: impl FnMut(usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        // The buffer has to be initialized, even though it is overwritten before it is read.
+        // The rng gets it as a `&mut [u8]`, and a custom rng is allowed to read from that, so
+        // an uninitialized buffer would be undefined behavior. Zeroing 64 bytes is also much
+        // cheaper than filling them with random data.
+        self.for_each_index_with(&mut [0u8; BUFFER_SIZE], count, f)
+    }
+
+    /// Like [`Generator::for_each_index`], but uses `buffer` as scratch space for random data
+    ///
+    /// The contents of `buffer` are overwritten before they are used, so it doesn't need to be
+    /// initialized.
+    #[inline]
+    fn for_each_index_with<E>(
+        &mut self,
+        buffer: &mut [u8; BUFFER_SIZE],
+        count: usize,
+        mut f: impl FnMut(usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        if self.sampling == Sampling::Uniform {
+            // `N` is never zero, so the range is never empty.
+            let dist = rand::distr::Uniform::new(0, N).unwrap();
+            for _ in 0..count {
+                f(self.random.sample(dist))?;
+            }
             return Ok(());
         }
-        debug_assert!(N.is_power_of_two());
-        let mask: usize = N - 1;
+        let mask = self.rejection_mask();
+        let min_mask = N.next_power_of_two() - 1;
+        if mask == min_mask {
+            // Pass the default mask as a constant, so the compiler can optimize the common case.
+            self.for_each_masked_index(buffer, count, min_mask, f)
+        } else {
+            self.for_each_masked_index(buffer, count, mask, f)
+        }
+    }
+
+    /// Call `f` with random indices from bytes masked with `mask`, using rejection sampling
+    #[inline(always)]
+    fn for_each_masked_index<E>(
+        &mut self,
+        buffer: &mut [u8; BUFFER_SIZE],
+        count: usize,
+        mask: usize,
+        mut f: impl FnMut(usize) -> Result<(), E>,
+    ) -> Result<(), E> {
         debug_assert!(mask.count_ones() == mask.trailing_ones());
-        let mut buffer = [0u8; BUFFER_SIZE];
-        let mut rem = self.size;
+        // The largest multiple of `N` that fits in the mask. With the default mask, this is
+        // just `N`.
+        let limit = (mask + 1) / N * N;
+        let mut rem = count;
         while rem > 0 {
-            let bytes = &mut buffer[..self.size.min(BUFFER_SIZE)];
+            // On average, only `limit` out of every `mask + 1` bytes map to a valid index, so
+            // draw the expected number of bytes for the remaining characters. If more are
+            // rejected, another batch is needed. If the alphabet size is a power of 2, this is
+            // exactly one byte per character.
+            let step = (rem.min(BUFFER_SIZE) * (mask + 1))
+                .div_ceil(limit)
+                .min(BUFFER_SIZE);
+            let bytes = &mut buffer[..step];
             // This generates more bits than we actually need, but using one byte per character
             // makes the implementation a lot simpler than tracking how many bits have been used.
             self.random.fill(bytes);
             for &b in &*bytes {
-                let idx = b as usize & mask;
-                debug_assert!(idx < N);
-                // Since the alphabet size is a power of 2, applying the
-                // mask ensures that idx is a valid index into the alphabet.
-                out.write_char(self.alphabet.0[idx])?;
+                let value = b as usize & mask;
+                // If the alphabet size isn't a power of 2, some values are out of range. Rejecting
+                // those, rather than only using a modulus, keeps the distribution uniform. With the
+                // default mask, `limit` is `N`, so the modulus isn't needed.
+                if value < limit {
+                    f(if limit == N { value } else { value % N })?;
+                    rem -= 1;
+                    if rem == 0 {
+                        break;
+                    }
+                }
             }
-            rem -= bytes.len();
         }
         Ok(())
     }
@@ -172,25 +875,892 @@ impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
     /// assert_eq!(f.to_string(), "kGAK7hvw7AdqTcsFNZGtr");
     ///
     /// ```
+    ///
+    /// Width, fill, alignment, and precision are supported the same way as for `str`:
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let mut generator = Generator::with_size(8);
+    ///
+    /// let right = format!("{:>12}", generator.fmt());
+    /// assert!(right.starts_with("    "));
+    /// assert!(!right[4..].contains(' '));
+    ///
+    /// let centered = format!("{:*^12}", generator.fmt());
+    /// assert!(centered.starts_with("**") && centered.ends_with("**"));
+    /// assert!(!centered[2..10].contains('*'));
+    ///
+    /// let truncated = format!("{:.4}", generator.fmt());
+    /// assert_eq!(truncated.len(), 4);
+    /// ```
     pub fn fmt(&mut self) -> Fmt<'_, 'a, R, N> {
         Fmt(RefCell::new(self))
     }
 
     /// Generate a random id as a string
     ///
+    /// The string is allocated with enough capacity to hold the id, even if
+    /// the alphabet contains multi-byte characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics or aborts if the string can't be allocated, for example if the size is absurdly
+    /// large. Use [`Generator::try_gen`] if the size isn't known to be reasonable.
+    ///
     /// # Examples
     ///
     /// ```
     /// let random_id = randoid::Generator::default().gen();
     /// ```
+    ///
+    /// ```
+    /// use randoid::{Generator, Alphabet};
+    ///
+    /// let emoji = Alphabet::new(['🦀', '🐍', '🐪', '🐹']);
+    /// let id = Generator::with_alphabet(&emoji).gen();
+    /// assert_eq!(id.len(), 21 * 4);
+    /// // The initial allocation was big enough, so the string never had to grow
+    /// assert_eq!(id.capacity(), 21 * 4);
+    /// ```
     #[cfg(any(feature = "std", feature = "alloc"))]
     pub fn gen(&mut self) -> String {
-        let mut res = String::with_capacity(self.size);
-        self.write_to(&mut res).unwrap();
+        let mut res = String::with_capacity(self.max_id_bytes());
+        self.push_to(&mut res);
         res
     }
 
-    /// Generate a random id as a smartstring
+    /// Generate a random id as a string, using a thread local buffer for random data
+    ///
+    /// This produces exactly the same id as [`Generator::gen`], but instead of a new buffer on
+    /// the stack, it uses a buffer that is shared by all calls to `gen_tls` on the current
+    /// thread, so it doesn't have to be zeroed for every id. This is only an optimization,
+    /// which may help when generating a lot of ids on the same threads, such as in a server.
+    /// Measure before relying on it.
+    ///
+    /// If the buffer is already in use, for example if the rng itself calls `gen_tls`, this
+    /// falls back to a buffer on the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let mut gen = Generator::with_alphabet(&HEX).size(16);
+    /// for _ in 0..1000 {
+    ///     let id = gen.gen_tls();
+    ///     assert_eq!(id.len(), 16);
+    ///     assert!(HEX.validate(&id));
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn gen_tls(&mut self) -> String {
+        let mut res = String::with_capacity(self.max_id_bytes());
+        let alphabet = self.alphabet;
+        let size = self.size;
+        let push = |idx| {
+            res.push(alphabet.chars[idx]);
+            Ok::<_, Infallible>(())
+        };
+        SCRATCH
+            .with(|scratch| match scratch.try_borrow_mut() {
+                Ok(mut buffer) => self.for_each_index_with(&mut buffer, size, push),
+                Err(_) => self.for_each_index(size, push),
+            })
+            .unwrap_or_else(|e| match e {});
+        res
+    }
+
+    /// Generate a random id as a string, or return an error if the id would be unreasonably
+    /// large
+    ///
+    /// This is like [`Generator::gen`], but returns an error instead of trying to allocate an
+    /// enormous string, if the size is misconfigured. The limit is [`DEFAULT_MAX_ID_BYTES`],
+    /// use [`Generator::try_gen_with_limit`] for a different limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::TooLarge`] if the id could be longer than
+    /// [`DEFAULT_MAX_ID_BYTES`], or allocating the string fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{ConfigError, Generator};
+    ///
+    /// assert_eq!(Generator::default().try_gen().unwrap().len(), 21);
+    /// assert_eq!(Generator::with_size(usize::MAX).try_gen(), Err(ConfigError::TooLarge));
+    /// assert_eq!(Generator::with_size(100_000).try_gen(), Err(ConfigError::TooLarge));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn try_gen(&mut self) -> Result<String, ConfigError> {
+        self.try_gen_with_limit(DEFAULT_MAX_ID_BYTES)
+    }
+
+    /// Generate a random id as a string, or return an error if the id could be longer than
+    /// `max_bytes`
+    ///
+    /// The limit is compared to [`Generator::max_id_bytes`], so for alphabets with multi-byte
+    /// characters, an id may be rejected even though it would happen to fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::TooLarge`] if the id could be longer than `max_bytes`,
+    /// or allocating the string fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{ConfigError, Generator};
+    ///
+    /// let mut gen = Generator::with_size(100_000);
+    /// assert_eq!(gen.try_gen_with_limit(1 << 20).unwrap().len(), 100_000);
+    /// assert_eq!(gen.try_gen_with_limit(1000), Err(ConfigError::TooLarge));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn try_gen_with_limit(&mut self, max_bytes: usize) -> Result<String, ConfigError> {
+        let len = self
+            .size
+            .checked_mul(self.alphabet.max_char_len())
+            .filter(|&len| len <= max_bytes)
+            .ok_or(ConfigError::TooLarge)?;
+        let mut res = String::new();
+        res.try_reserve_exact(len)
+            .map_err(|_| ConfigError::TooLarge)?;
+        self.push_to(&mut res);
+        Ok(res)
+    }
+
+    /// Generate a new random id, and append it to `out`
+    ///
+    /// This gives the same result as [`Generator::write_to`], but pushes characters directly
+    /// onto the string, rather than going through [`fmt::Write`], so it can't fail, and is
+    /// a little faster.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let mut id = String::from("user_");
+    /// Generator::with_size(8).push_to(&mut id);
+    /// assert_eq!(id.len(), 13);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline]
+    pub fn push_to(&mut self, out: &mut String) {
+        let alphabet = self.alphabet;
+        out.reserve(self.max_id_bytes());
+        if alphabet.is_ascii() {
+            self.push_ascii(out);
+            return;
+        }
+        self.for_each_index(self.size, |idx| {
+            out.push(alphabet.chars[idx]);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+    }
+
+    /// Append an id to `out`, for an ASCII alphabet
+    ///
+    /// Pushing one `char` at a time has to check how many bytes it takes every time. Instead,
+    /// this collects the characters in a buffer of bytes, and appends the whole buffer at
+    /// once.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline]
+    fn push_ascii(&mut self, out: &mut String) {
+        /// View ASCII bytes as a `str`
+        fn ascii_str(bytes: &[u8]) -> &str {
+            debug_assert!(bytes.is_ascii());
+            // SAFETY: Only ASCII characters are written to the buffer, and a string of ASCII
+            // characters is always valid UTF-8.
+            unsafe { core::str::from_utf8_unchecked(bytes) }
+        }
+
+        let alphabet = self.alphabet;
+        debug_assert!(alphabet.is_ascii());
+        let mut chunk = [0u8; BUFFER_SIZE];
+        let mut len = 0;
+        self.for_each_index(self.size, |idx| {
+            // The alphabet is ASCII, so this cast doesn't truncate.
+            chunk[len] = alphabet.chars[idx] as u8;
+            len += 1;
+            if len == BUFFER_SIZE {
+                out.push_str(ascii_str(&chunk));
+                len = 0;
+            }
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        out.push_str(ascii_str(&chunk[..len]));
+    }
+
+    /// Generate a new random id, and append it to `out` encoded as UTF-8
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, Alphabet};
+    ///
+    /// let mut buf = b"id=".to_vec();
+    /// Generator::with_size(8).extend_bytes(&mut buf);
+    /// assert_eq!(buf.len(), 11);
+    ///
+    /// let mut buf = Vec::new();
+    /// Generator::with_alphabet(&Alphabet::new(['é', 'ü'])).size(4).extend_bytes(&mut buf);
+    /// assert_eq!(std::str::from_utf8(&buf).unwrap().chars().count(), 4);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline]
+    pub fn extend_bytes(&mut self, out: &mut Vec<u8>) {
+        let alphabet = self.alphabet;
+        out.reserve(self.max_id_bytes());
+        self.for_each_index(self.size, |idx| {
+            let c = alphabet.chars[idx];
+            if c.is_ascii() {
+                out.push(c as u8);
+            } else {
+                out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+    }
+
+    /// Generate a random id whose first character isn't one of `forbidden_first`
+    ///
+    /// This is useful if the id will be used somewhere that a leading character such as
+    /// `-` is problematic. The first character is chosen uniformly from the characters of
+    /// the alphabet that aren't forbidden, and the remaining characters are generated as
+    /// usual.
+    ///
+    /// Note that this reduces the entropy of the id: the first character only
+    /// contributes `log2(N - k)` bits rather than `log2(N)` bits, where `k` is the number of
+    /// forbidden characters that are in the alphabet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if every character of the alphabet is forbidden, and the size is not zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut gen = randoid::Generator::default();
+    /// for _ in 0..1000 {
+    ///     let id = gen.gen_no_leading(&['_', '-']);
+    ///     assert_eq!(id.len(), 21);
+    ///     assert!(!id.starts_with(['_', '-']));
+    /// }
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[track_caller]
+    pub fn gen_no_leading(&mut self, forbidden_first: &[char]) -> String {
+        let mut res = String::with_capacity(self.max_id_bytes());
+        if self.size == 0 {
+            return res;
+        }
+        assert!(
+            self.alphabet
+                .chars
+                .iter()
+                .any(|c| !forbidden_first.contains(c)),
+            "Every character of the alphabet is forbidden"
+        );
+        loop {
+            let first = self.alphabet.chars[self.random_index()];
+            // Retrying until we get an allowed character keeps the first character
+            // uniformly distributed among the allowed characters.
+            if !forbidden_first.contains(&first) {
+                res.push(first);
+                break;
+            }
+        }
+        self.write_n(self.size - 1, &mut res).unwrap();
+        res
+    }
+
+    /// Generate an id that doesn't start with any of `prefixes`
+    ///
+    /// Generates up to `max_tries` ids, and returns the first one that doesn't start with
+    /// one of the reserved prefixes, or `None` if all of them did.
+    ///
+    /// This is useful if ids are used somewhere that certain prefixes have special meaning,
+    /// such as a url path where `api` or `admin` are reserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, Alphabet};
+    ///
+    /// let alphabet = Alphabet::new(['a', 'b']);
+    /// let mut gen = Generator::with_alphabet(&alphabet).size(4);
+    /// for _ in 0..100 {
+    ///     let id = gen.gen_not_starting_with(&["a"], 100).unwrap();
+    ///     assert!(id.starts_with('b'));
+    /// }
+    ///
+    /// assert_eq!(gen.gen_not_starting_with(&["a", "b"], 10), None);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_not_starting_with(&mut self, prefixes: &[&str], max_tries: usize) -> Option<String> {
+        (0..max_tries)
+            .map(|_| self.gen())
+            .find(|id| !prefixes.iter().any(|p| id.starts_with(p)))
+    }
+
+    /// Generate an id that matches the regular expression `re`
+    ///
+    /// Generates up to `max_tries` ids, and returns the first one that `re` matches, or `None`
+    /// if none of them did. This can express constraints that are hard to build into the
+    /// alphabet, such as requiring at least one digit.
+    ///
+    /// The id is checked with [`Regex::is_match`](regex::Regex::is_match), so the pattern
+    /// only has to match part of the id, unless it is anchored with `^` and `$`.
+    ///
+    /// Every attempt is a completely new id, so the number of attempts needed depends on what
+    /// fraction of ids match. A strict pattern might almost never match, or even be impossible
+    /// with the alphabet and size, so choose `max_tries` with that in mind, and handle `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::BASE62};
+    /// use regex::Regex;
+    ///
+    /// let mut gen = Generator::with_alphabet(&BASE62).size(8);
+    /// // At least two digits
+    /// let re = Regex::new("[0-9].*[0-9]").unwrap();
+    /// let id = gen.gen_matching_regex(&re, 100).unwrap();
+    /// assert!(id.chars().filter(char::is_ascii_digit).count() >= 2);
+    ///
+    /// // BASE62 doesn't have any punctuation
+    /// assert_eq!(gen.gen_matching_regex(&Regex::new("_").unwrap(), 100), None);
+    /// ```
+    #[cfg(feature = "regex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+    pub fn gen_matching_regex(&mut self, re: &regex::Regex, max_tries: usize) -> Option<String> {
+        (0..max_tries)
+            .map(|_| self.gen())
+            .find(|id| re.is_match(id))
+    }
+
+    /// Generate an id made up of several parts, and write it to `out`
+    ///
+    /// Each part is either a literal string, or a number of random characters from the
+    /// alphabet. The parts are separated by `sep`. The configured size isn't used.
+    ///
+    /// See [`Generator::gen_parts`].
+    pub fn write_parts_to<W: Write + ?Sized>(
+        &mut self,
+        out: &mut W,
+        parts: &[Part<'_>],
+        sep: &str,
+    ) -> fmt::Result {
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                out.write_str(sep)?;
+            }
+            match *part {
+                Part::Literal(s) => out.write_str(s)?,
+                Part::Random(len) => self.write_n(len, out)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate an id made up of several parts, as a string
+    ///
+    /// This is useful for composite keys, such as `<region>-<shard>-<random>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, Part};
+    ///
+    /// let id = Generator::default().gen_parts(
+    ///     &[Part::Literal("us"), Part::Random(8), Part::Random(4)],
+    ///     "~",
+    /// );
+    /// let pieces: Vec<&str> = id.split('~').collect();
+    /// assert_eq!(pieces.len(), 3);
+    /// assert_eq!(pieces[0], "us");
+    /// assert_eq!(pieces[1].len(), 8);
+    /// assert_eq!(pieces[2].len(), 4);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_parts(&mut self, parts: &[Part<'_>], sep: &str) -> String {
+        let mut res = String::new();
+        self.write_parts_to(&mut res, parts, sep).unwrap();
+        res
+    }
+
+    /// Generate an id made of `groups` groups of `group_len` random characters, separated by
+    /// `sep`, like a license key
+    ///
+    /// This ignores the configured size. The id has `groups * group_len` random characters,
+    /// plus the separators between groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::BASE58};
+    ///
+    /// let key = Generator::with_alphabet(&BASE58).gen_license_key(4, 5, '-');
+    /// assert_eq!(key.len(), 23);
+    /// let groups: Vec<&str> = key.split('-').collect();
+    /// assert_eq!(groups.len(), 4);
+    /// assert!(groups.iter().all(|g| g.len() == 5 && BASE58.validate(g)));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_license_key(&mut self, groups: usize, group_len: usize, sep: char) -> String {
+        let alphabet = self.alphabet;
+        let count = groups.saturating_mul(group_len);
+        let seps = groups.saturating_sub(1);
+        let mut res = String::with_capacity(
+            count
+                .saturating_mul(alphabet.max_char_len())
+                .saturating_add(seps.saturating_mul(sep.len_utf8())),
+        );
+        let mut written = 0;
+        self.for_each_index(count, |idx| {
+            if written > 0 && written % group_len == 0 {
+                res.push(sep);
+            }
+            res.push(alphabet.chars[idx]);
+            written += 1;
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        res
+    }
+
+    /// Generate an id that takes `total_bytes` bytes as UTF-8, instead of a number of
+    /// characters
+    ///
+    /// This ignores the configured size. It is useful for storing ids in fixed size columns or
+    /// fields. With an ASCII alphabet, the id has exactly `total_bytes` characters.
+    ///
+    /// With multi-byte characters, the id never takes more than `total_bytes` bytes. Characters
+    /// are drawn as usual until there is less room left than the longest character. After that,
+    /// a character that doesn't fit in the remaining bytes is skipped, and another one is drawn
+    /// instead. This means that the last few characters are biased towards shorter characters.
+    /// When there is less room left than the shortest character, the id is finished, so it is
+    /// shorter than `total_bytes` if no combination of characters adds up to it exactly, for
+    /// example an odd number of bytes with an alphabet of two-byte characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, Alphabet, alphabet::BASE58};
+    ///
+    /// let id = Generator::with_alphabet(&BASE58).gen_fixed_bytes(16);
+    /// assert_eq!(id.len(), 16);
+    ///
+    /// let mixed = Alphabet::new(['a', 'b', 'é', '€']);
+    /// let id = Generator::with_alphabet(&mixed).gen_fixed_bytes(16);
+    /// assert_eq!(id.len(), 16);
+    ///
+    /// // Every character takes two bytes, so 7 bytes can only fit 3 characters
+    /// let accents = Alphabet::new(['é', 'è', 'ê', 'ë']);
+    /// let id = Generator::with_alphabet(&accents).gen_fixed_bytes(7);
+    /// assert_eq!(id.len(), 6);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_fixed_bytes(&mut self, total_bytes: usize) -> String {
+        let alphabet = self.alphabet;
+        let min_len = alphabet.min_char_len();
+        let max_len = alphabet.max_char_len();
+        let mut res = String::with_capacity(total_bytes);
+        while total_bytes - res.len() >= min_len {
+            // Draw as many characters as are sure to fit, or one at a time near the end.
+            let count = ((total_bytes - res.len()) / max_len).max(1);
+            self.for_each_index(count, |idx| {
+                let c = alphabet.chars[idx];
+                if c.len_utf8() <= total_bytes - res.len() {
+                    res.push(c);
+                }
+                Ok::<_, Infallible>(())
+            })
+            .unwrap_or_else(|e| match e {});
+        }
+        res
+    }
+
+    /// Generate a new id, along with the position in the alphabet of its first character
+    ///
+    /// Like every character of the id, the first character is chosen uniformly from the whole
+    /// alphabet, so the index can be used to distribute ids evenly, for example to choose a
+    /// shard, without looking the character up in the alphabet again. The index is `None` if
+    /// the size is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::DEFAULT};
+    ///
+    /// let (id, first) = Generator::default().gen_with_first_index();
+    /// let first = first.unwrap();
+    /// assert_eq!(id.chars().next(), Some(DEFAULT.as_chars()[first]));
+    /// let shard = first % 4;
+    /// # assert!(shard < 4);
+    ///
+    /// assert_eq!(Generator::with_size(0).gen_with_first_index(), (String::new(), None));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_with_first_index(&mut self) -> (String, Option<usize>) {
+        let alphabet = self.alphabet;
+        let mut res = String::with_capacity(self.max_id_bytes());
+        let mut first = None;
+        self.for_each_index(self.size, |idx| {
+            first.get_or_insert(idx);
+            res.push(alphabet.chars[idx]);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        (res, first)
+    }
+
+    /// Generate a new id with an extra parity character at the end
+    ///
+    /// The id has `size` random characters, followed by the character at the sum of their
+    /// positions in the alphabet, modulo the length of the alphabet. The parity character can
+    /// be checked with [`Alphabet::verify_parity`], to catch typos in ids entered by hand.
+    ///
+    /// This is much lighter than a real checksum. If every character of the alphabet is unique,
+    /// changing any single character is always detected, but swapping two characters never is,
+    /// and other errors are only detected most of the time. The parity character doesn't add
+    /// any entropy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::NUMERIC};
+    ///
+    /// let code = Generator::with_alphabet(&NUMERIC).size(6).gen_with_parity_bit();
+    /// assert_eq!(code.len(), 7);
+    /// assert!(NUMERIC.verify_parity(&code));
+    ///
+    /// let mut typo: Vec<char> = code.chars().collect();
+    /// typo[2] = if typo[2] == '0' { '1' } else { '0' };
+    /// let typo: String = typo.into_iter().collect();
+    /// assert!(!NUMERIC.verify_parity(&typo));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_with_parity_bit(&mut self) -> String {
+        let alphabet = self.alphabet;
+        let mut res = String::with_capacity(self.max_id_bytes() + alphabet.max_char_len());
+        let mut sum = 0;
+        self.for_each_index(self.size, |idx| {
+            sum = (sum + idx) % N;
+            res.push(alphabet.chars[idx]);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        res.push(alphabet.chars[sum]);
+        res
+    }
+
+    /// Generate a new id, along with a redacted form of it that is suitable for logs
+    ///
+    /// The redacted form keeps the first `prefix` and last `suffix` characters of the id, and
+    /// replaces the rest with `…`. That is usually enough to tell ids apart when reading logs,
+    /// without storing the full id. If `prefix + suffix` is at least the length of the id, the
+    /// whole id is replaced with `…`, so the redacted form never contains the full id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let (id, redacted) = Generator::default().gen_with_redaction(4, 2);
+    /// assert_eq!(redacted.chars().count(), 7);
+    /// assert_eq!(redacted[..4], id[..4]);
+    /// assert_eq!(&redacted[4..7], "…");
+    /// assert_eq!(redacted[7..], id[19..]);
+    ///
+    /// let (_, redacted) = Generator::with_size(6).gen_with_redaction(4, 2);
+    /// assert_eq!(redacted, "…");
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_with_redaction(&mut self, prefix: usize, suffix: usize) -> (String, String) {
+        let id = self.gen();
+        let mut redacted = String::new();
+        if prefix.saturating_add(suffix) < self.size {
+            redacted.extend(id.chars().take(prefix));
+            redacted.push('…');
+            redacted.extend(id.chars().skip(self.size - suffix));
+        } else {
+            redacted.push('…');
+        }
+        (id, redacted)
+    }
+
+    /// Generate a public id and a secret token together
+    ///
+    /// The public id uses the generator's size and alphabet, and the secret has `secret_size`
+    /// characters from `secret_alphabet`. Both are generated with the generator's rng.
+    ///
+    /// This is useful for things like share links, which need both an id to identify them and
+    /// a secret to authorize access. The two are independent random values, so the secret can't
+    /// be derived from the public id, or vice versa.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::BASE58};
+    ///
+    /// let mut gen = Generator::with_size(12);
+    /// let (public, secret) = gen.gen_linked(32, &BASE58);
+    /// assert_eq!(public.len(), 12);
+    /// assert_eq!(secret.len(), 32);
+    /// assert!(!secret.starts_with(&public));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_linked<const M: usize>(
+        &mut self,
+        secret_size: usize,
+        secret_alphabet: &Alphabet<M>,
+    ) -> (String, String) {
+        let public = self.gen();
+        let secret = Generator::new(secret_size, secret_alphabet, &mut self.random)
+            .sampling(self.sampling)
+            .gen();
+        (public, secret)
+    }
+
+    /// Replace everything after the first `keep_prefix_len` bytes of `id` with a new random id
+    ///
+    /// `id` is truncated to `keep_prefix_len` bytes, and then `size` new random characters are
+    /// appended. This reuses the allocation of `id`, which is useful for rotating tokens that
+    /// have a fixed prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keep_prefix_len` is greater than the length of `id`, or doesn't lie on a
+    /// character boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let mut gen = Generator::with_size(16);
+    /// let mut token = String::from("tok_");
+    /// gen.write_to(&mut token).unwrap();
+    ///
+    /// for _ in 0..2 {
+    ///     let old = token.clone();
+    ///     gen.regenerate_suffix(&mut token, 4);
+    ///     assert!(token.starts_with("tok_"));
+    ///     assert_eq!(token.len(), 20);
+    ///     assert_ne!(token, old);
+    /// }
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[track_caller]
+    pub fn regenerate_suffix(&mut self, id: &mut String, keep_prefix_len: usize) {
+        id.truncate(keep_prefix_len);
+        assert_eq!(
+            id.len(),
+            keep_prefix_len,
+            "prefix length is longer than the id"
+        );
+        id.reserve(self.max_id_bytes());
+        self.write_to(id).unwrap();
+    }
+
+    /// Generate a new random id along with a hash of it
+    ///
+    /// The hash is computed by `hasher` using
+    /// [`BuildHasher::hash_one`](core::hash::BuildHasher::hash_one), so it is the same hash that
+    /// a `HashMap` using `hasher` would compute for the id. This is useful for storing a hash of
+    /// the id instead of the id itself.
+    ///
+    /// There is no default hasher, because which one is appropriate depends on how the hash is
+    /// used. In particular, if the hash is stored, it must be stable, which is not true of
+    /// [`RandomState`](std::collections::hash_map::RandomState), or across Rust versions of
+    /// [`DefaultHasher`](std::collections::hash_map::DefaultHasher).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{BuildHasher, BuildHasherDefault};
+    /// use randoid::Generator;
+    ///
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let (id, hash) = Generator::default().gen_with_hash(&hasher);
+    /// assert_eq!(hasher.hash_one(&id), hash);
+    /// assert_eq!(hasher.hash_one(id.as_str()), hash);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_with_hash<B: core::hash::BuildHasher>(&mut self, hasher: &B) -> (String, u64) {
+        let id = self.gen();
+        let hash = hasher.hash_one(&id);
+        (id, hash)
+    }
+
+    /// Generate `n` new random ids, sorted by the order of characters in the alphabet
+    ///
+    /// Ids are compared character by character, where a character is less than another if it
+    /// comes earlier in the alphabet. This is the same as the usual string ordering only if the
+    /// alphabet is itself sorted, which isn't true of [`DEFAULT`](alphabet::DEFAULT).
+    ///
+    /// This is just a convenience for generating and sorting ids. The ids aren't guaranteed to
+    /// be unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let ids = Generator::with_alphabet(&HEX).size(6).gen_sorted_n(100);
+    /// assert_eq!(ids.len(), 100);
+    /// assert!(ids.windows(2).all(|pair| pair[0] <= pair[1]));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_sorted_n(&mut self, n: usize) -> Vec<String> {
+        // The alphabet has at most 255 characters, so every index fits in a byte.
+        let mut indices: Vec<Vec<u8>> = (0..n)
+            .map(|_| {
+                let mut id = Vec::with_capacity(self.size);
+                self.for_each_index(self.size, |idx| {
+                    id.push(idx as u8);
+                    Ok::<_, Infallible>(())
+                })
+                .unwrap_or_else(|e| match e {});
+                id
+            })
+            .collect();
+        indices.sort_unstable();
+        indices
+            .into_iter()
+            .map(|id| {
+                id.into_iter()
+                    .map(|idx| self.alphabet.chars[usize::from(idx)])
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Generate a new random id, with the case of each ASCII letter chosen at random
+    ///
+    /// Each ASCII letter in the id is converted to uppercase or lowercase with equal
+    /// probability, using the generator's rng. Other characters are unaffected.
+    ///
+    /// This is intended for alphabets that only contain lowercase (or only uppercase) letters.
+    /// Each letter in the id adds one bit of entropy, so with a 26 letter alphabet, each
+    /// character has about 5.7 bits of entropy instead of 4.7. If the alphabet already
+    /// contains both cases of a letter, this doesn't add any entropy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, Alphabet};
+    ///
+    /// let lower = Alphabet::<26>::from_range('a', 'z');
+    /// let id = Generator::with_alphabet(&lower).size(64).gen_random_case();
+    /// assert!(id.chars().all(|c| c.is_ascii_alphabetic()));
+    /// assert!(id.contains(|c: char| c.is_ascii_uppercase()));
+    /// assert!(id.contains(|c: char| c.is_ascii_lowercase()));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_random_case(&mut self) -> String {
+        let id = self.gen();
+        let mut res = String::with_capacity(id.len());
+        let mut bits = 0u64;
+        let mut available = 0;
+        for c in id.chars() {
+            if !c.is_ascii_alphabetic() {
+                res.push(c);
+                continue;
+            }
+            if available == 0 {
+                bits = self.random.next_u64();
+                available = 64;
+            }
+            res.push(if bits & 1 == 0 {
+                c.to_ascii_lowercase()
+            } else {
+                c.to_ascii_uppercase()
+            });
+            bits >>= 1;
+            available -= 1;
+        }
+        res
+    }
+
+    /// Generate an id made of `value` encoded in the alphabet, followed by random characters
+    ///
+    /// The prefix is `value` encoded as a base `N` number using [`Alphabet::write_u128`], and is
+    /// followed by `size` random characters. This can be used to create ids that combine
+    /// a sequential part, such as a counter, with a random part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet has fewer than 2 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let mut gen = Generator::with_alphabet(&HEX).size(8);
+    /// let id = gen.gen_base_n(0x1f);
+    /// assert_eq!(id.len(), 10);
+    /// assert!(id.starts_with("1f"));
+    /// assert_eq!(HEX.decode_u128(&id[..2]), Some(0x1f));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[track_caller]
+    pub fn gen_base_n(&mut self, value: u128) -> String {
+        let mut res = self.alphabet.encode_u128(value);
+        res.reserve(self.max_id_bytes());
+        self.write_to(&mut res).unwrap();
+        res
+    }
+
+    /// Generate a random lowercase hexadecimal string of `size` characters
+    ///
+    /// This ignores the generator's alphabet and size, and uses each random byte for two
+    /// hex digits, so it only needs half as much random data as generating an id with the
+    /// [`HEX`](alphabet::HEX) alphabet, and doesn't need to look up characters in the alphabet.
+    ///
+    /// If `size` is odd, only the high nibble of the last byte is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut gen = randoid::Generator::default();
+    /// let id = gen.hex_fast(32);
+    /// assert_eq!(id.len(), 32);
+    /// assert!(id.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+    ///
+    /// assert_eq!(gen.hex_fast(7).len(), 7);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn hex_fast(&mut self, size: usize) -> String {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut res = String::with_capacity(size);
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let mut rem = size;
+        while rem > 0 {
+            let bytes = &mut buffer[..rem.div_ceil(2).min(BUFFER_SIZE)];
+            self.random.fill(bytes);
+            for &b in &*bytes {
+                res.push(DIGITS[(b >> 4) as usize] as char);
+                if rem > 1 {
+                    res.push(DIGITS[(b & 0xf) as usize] as char);
+                }
+                rem = rem.saturating_sub(2);
+            }
+        }
+        res
+    }
+
+    /// Generate a random id as a smartstring
     ///
     /// # Examples
     ///
@@ -207,27 +1777,326 @@ impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
         self.write_to(&mut res).unwrap();
         res
     }
+
+    /// Generate a random id as an [`ArrayString`](arrayvec::ArrayString)
+    ///
+    /// The id is stored inline, with a capacity of `CAP` bytes, so this never allocates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an id might not fit in `CAP` bytes, that is, if
+    /// [`Generator::max_id_bytes`] is greater than `CAP`. The id is never truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayvec::ArrayString;
+    /// use randoid::Generator;
+    ///
+    /// let id: ArrayString<24> = Generator::default().gen_arrayvec().unwrap();
+    /// assert_eq!(id.len(), 21);
+    ///
+    /// assert!(Generator::with_size(32).gen_arrayvec::<24>().is_err());
+    /// ```
+    #[cfg(feature = "arrayvec")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+    pub fn gen_arrayvec<const CAP: usize>(
+        &mut self,
+    ) -> Result<arrayvec::ArrayString<CAP>, arrayvec::CapacityError> {
+        if self.max_id_bytes() > CAP {
+            return Err(arrayvec::CapacityError::new(()));
+        }
+        let alphabet = self.alphabet;
+        let mut res = arrayvec::ArrayString::new();
+        self.for_each_index(self.size, |idx| {
+            // There is always room, since the id is at most `max_id_bytes` long.
+            res.push(alphabet.chars[idx]);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        Ok(res)
+    }
+
+    /// Generate a random id as ASCII bytes in a [`SmallVec`](smallvec::SmallVec)
+    ///
+    /// If the size is at most `INLINE`, the id is stored inline without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonAsciiAlphabet`] if the alphabet contains any non-ASCII characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let id = Generator::with_size(16).gen_smallvec::<24>().unwrap();
+    /// assert_eq!(id.len(), 16);
+    /// assert!(!id.spilled());
+    ///
+    /// let id = Generator::with_size(32).gen_smallvec::<24>().unwrap();
+    /// assert_eq!(id.len(), 32);
+    /// assert!(id.spilled());
+    /// ```
+    #[cfg(feature = "smallvec")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+    pub fn gen_smallvec<const INLINE: usize>(
+        &mut self,
+    ) -> Result<smallvec::SmallVec<[u8; INLINE]>, NonAsciiAlphabet> {
+        if !self.alphabet.is_ascii() {
+            return Err(NonAsciiAlphabet);
+        }
+        let alphabet = self.alphabet;
+        let mut res = smallvec::SmallVec::with_capacity(self.size);
+        self.for_each_index(self.size, |idx| {
+            // The alphabet is ASCII, so this cast doesn't truncate.
+            res.push(alphabet.chars[idx] as u8);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        Ok(res)
+    }
+}
+
+/// Bytes of a `str` being overwritten with ASCII characters
+///
+/// When dropped, any bytes that haven't been written yet are replaced with `0`, so the
+/// string is valid UTF-8 even if writing is interrupted by a panic.
+struct AsciiFill<'b> {
+    bytes: &'b mut [u8],
+    pos: usize,
+}
+
+impl Drop for AsciiFill<'_> {
+    fn drop(&mut self) {
+        self.bytes[self.pos..].fill(b'0');
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'a, R: rand::TryRngCore, const N: usize> Generator<'a, R, N> {
+    /// Generate a new id using a fallible source of random data, and append it to `out`
+    ///
+    /// This works with rngs that only implement [`TryRngCore`](rand::TryRngCore), such as
+    /// [`OsRng`](rand::rngs::OsRng). If the rng fails part way through the id, `out` is
+    /// truncated back to its original length, so it never contains a partial id.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from the rng, if it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let mut gen = Generator::new(16, &HEX, rand::rngs::OsRng);
+    /// let mut ids = String::from("id=");
+    /// gen.try_write_to(&mut ids).unwrap();
+    /// assert_eq!(ids.len(), 19);
+    /// ```
+    pub fn try_write_to(&mut self, out: &mut String) -> Result<(), R::Error> {
+        let start = out.len();
+        let alphabet = self.alphabet;
+        let size = self.size;
+        let error = core::cell::Cell::new(None);
+        let mut gen = Generator {
+            alphabet,
+            random: CatchErr {
+                inner: &mut self.random,
+                error: &error,
+            },
+            size,
+            sampling: self.sampling,
+            rejection_bits: self.rejection_bits,
+        };
+        out.reserve(gen.max_id_bytes());
+        // The rng is only used right before `f` is called, so checking for an error there
+        // catches it before any data from a failed call is used.
+        let res = gen.for_each_index(size, |idx| match error.take() {
+            Some(e) => Err(e),
+            None => {
+                out.push(alphabet.chars[idx]);
+                Ok(())
+            }
+        });
+        if res.is_err() {
+            out.truncate(start);
+        }
+        res
+    }
+}
+
+/// Adapts a fallible rng to `RngCore`, saving the first error for the caller to check
+#[cfg(any(feature = "std", feature = "alloc"))]
+struct CatchErr<'r, R: rand::TryRngCore> {
+    inner: &'r mut R,
+    error: &'r core::cell::Cell<Option<R::Error>>,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<R: rand::TryRngCore> CatchErr<'_, R> {
+    /// Save `error`, unless an earlier error hasn't been checked yet
+    fn save(&self, error: R::Error) {
+        let first = self.error.take().unwrap_or(error);
+        self.error.set(Some(first));
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<R: rand::TryRngCore> rand::RngCore for CatchErr<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.inner.try_next_u32().unwrap_or_else(|e| {
+            self.save(e);
+            0
+        })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.inner.try_next_u64().unwrap_or_else(|e| {
+            self.save(e);
+            0
+        })
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = self.inner.try_fill_bytes(dest) {
+            self.save(e);
+            dest.fill(0);
+        }
+    }
 }
 
-impl<'a, R: Rng> Generator<'a, R> {
+impl<'a, R> Generator<'a, R> {
     /// Create a new randoid generator from an Rng
     ///
     /// Using the default size and alphabet
+    ///
+    /// This is the equivalent of [`Generator::default()`] for when the `std-rand` feature
+    /// isn't enabled, or you want to use a different source of random data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    /// use rand::RngCore;
+    ///
+    /// /// Not very random, but it doesn't need `std`
+    /// struct Counter(u8);
+    ///
+    /// impl RngCore for Counter {
+    ///     fn next_u32(&mut self) -> u32 {
+    ///         let mut buf = [0; 4];
+    ///         self.fill_bytes(&mut buf);
+    ///         u32::from_le_bytes(buf)
+    ///     }
+    ///     fn next_u64(&mut self) -> u64 {
+    ///         self.next_u32() as u64
+    ///     }
+    ///     fn fill_bytes(&mut self, dest: &mut [u8]) {
+    ///         for b in dest {
+    ///             *b = self.0;
+    ///             self.0 = self.0.wrapping_add(1);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut gen = Generator::with_random(Counter(0));
+    /// assert_eq!(gen.gen(), "_-0123456789abcdefghi");
+    /// ```
     pub fn with_random(random: R) -> Self {
         Self {
             alphabet: &alphabet::DEFAULT,
             random,
             size: DEFAULT_SIZE,
+            sampling: Sampling::Bytes,
+            rejection_bits: None,
         }
     }
 }
 
+/// A part of a composite id
+///
+/// See [`Generator::gen_parts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part<'s> {
+    /// A fixed string
+    Literal(&'s str),
+    /// The given number of random characters
+    Random(usize),
+}
+
+/// Iterator that generates new ids forever
+///
+/// Created by the [`IntoIterator`] implementation for [`Generator`].
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::HEX};
+/// # use rand::SeedableRng;
+///
+/// let rand = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0x04040404);
+/// let ids: Vec<String> = Generator::new(8, &HEX, rand).into_iter().take(5).collect();
+/// assert_eq!(ids.len(), 5);
+/// assert_eq!(ids[0], "905c2761");
+/// assert_eq!(ids[1], "304ec655");
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Clone)]
+pub struct IntoIter<'a, R, const N: usize = 64>(Generator<'a, R, N>);
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'a, R: Rng, const N: usize> Iterator for IntoIter<'a, R, N> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        Some(self.0.gen())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<R: Rng, const N: usize> core::iter::FusedIterator for IntoIter<'_, R, N> {}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'a, R: Rng, const N: usize> IntoIterator for Generator<'a, R, N> {
+    type Item = String;
+    type IntoIter = IntoIter<'a, R, N>;
+
+    fn into_iter(self) -> IntoIter<'a, R, N> {
+        IntoIter(self)
+    }
+}
+
 /// See [`Generator::fmt`]
 pub struct Fmt<'g, 'a: 'g, R: Rng, const N: usize>(RefCell<&'g mut Generator<'a, R, N>>);
 
 impl<'g, 'a: 'g, R: Rng, const N: usize> fmt::Display for Fmt<'g, 'a, R, N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.borrow_mut().write_to(f)
+        let mut gen = self.0.borrow_mut();
+        // Like `str`, the precision is the maximum number of characters to write.
+        let len = f.precision().map_or(gen.size, |p| p.min(gen.size));
+        // Every id has exactly `len` characters, so we can figure out the padding
+        // without having to generate the id first.
+        let pad = f.width().map_or(0, |w| w.saturating_sub(len));
+        let (pre, post) = match f.align() {
+            Some(fmt::Alignment::Right) => (pad, 0),
+            Some(fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+            Some(fmt::Alignment::Left) | None => (0, pad),
+        };
+        let fill = f.fill();
+        for _ in 0..pre {
+            f.write_char(fill)?;
+        }
+        gen.write_n(len, f)?;
+        for _ in 0..post {
+            f.write_char(fill)?;
+        }
+        Ok(())
     }
 }
 
@@ -243,7 +2112,13 @@ impl<'g, 'a: 'g, R: Rng, const N: usize> fmt::Display for Fmt<'g, 'a, R, N> {
 /// The second argument is the alphabet to use. This macro will automatically add borrow the
 /// alphabet, if an owned value is passed. Defaults to [`alphabet::DEFAULT`].
 ///
-/// The third argument is the random number generator to use. Defaults to [`rand::thread_rng()`].
+/// The third argument is the random number generator to use. Defaults to [`rand::rng()`].
+///
+/// Instead of an alphabet and rng, `seed: <u64>` can be passed to use the default alphabet
+/// with a [`StdRng`](rand::rngs::StdRng) seeded from that value. This generates the same id
+/// every time, which is useful for examples and tests. `StdRng` isn't guaranteed to produce
+/// the same output for different versions of `rand`, so the ids may change when `rand` is
+/// upgraded.
 ///
 ///
 /// # Examples
@@ -265,24 +2140,47 @@ impl<'g, 'a: 'g, R: Rng, const N: usize> fmt::Display for Fmt<'g, 'a, R, N> {
 /// let id = randoid!(32, &randoid::alphabet::HEX);
 /// assert_eq!(id.len(), 32);
 /// assert!(id.chars().all(|c| c.is_ascii_hexdigit()));
-/// // Generate id with 32 hex characters, using SmallRng for the RNG
-/// let id = randoid!(32, &randoid::alphabet::HEX, StdRng::from_entropy());
+/// // Generate id with 32 hex characters, using StdRng for the RNG
+/// let id = randoid!(32, &randoid::alphabet::HEX, StdRng::from_os_rng());
 /// assert_eq!(id.len(), 32);
 /// assert!(id.chars().all(|c| c.is_ascii_hexdigit()));
 ///
 ///
 /// ```
+///
+/// Reproducible ids from a seed:
+///
+/// ```
+/// use randoid::randoid;
+///
+/// assert_eq!(randoid!(seed: 42), "wyBwxRa4XfHZh8K8fOfsL");
+/// assert_eq!(randoid!(seed: 42), randoid!(seed: 42));
+/// assert_ne!(randoid!(seed: 42), randoid!(seed: 43));
+/// // With a size
+/// assert_eq!(randoid!(8, seed: 7), "YVCEDu05");
+/// ```
 #[cfg(feature = "std-rand")]
 #[macro_export]
 macro_rules! randoid {
     () => {
         $crate::randoid()
     };
+    (seed: $seed:expr) => {
+        $crate::randoid!($crate::DEFAULT_SIZE, seed: $seed)
+    };
+    ($size:expr, seed: $seed:expr) => {
+        $crate::Generator::new(
+            $size,
+            &$crate::alphabet::DEFAULT,
+            <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64($seed),
+        )
+        .gen()
+    };
     ($size:expr) => {
         $crate::Generator::with_size($size).gen()
     };
     ($size:expr, &$alphabet:expr) => {
-        $crate::Generator::new($size, &$alphabet, rand::thread_rng()).gen()
+        $crate::Generator::new($size, &$alphabet, rand::rng()).gen()
     };
     ($size:expr, [$($alphabet:literal),+]) => {
         randoid!($size, &$crate::alphabet::Alphabet::new([$($alphabet),+]))
@@ -0,0 +1,233 @@
+//! Random looking ids derived from a sequence number.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::keyed::{splitmix64, GOLDEN_GAMMA};
+use crate::{Alphabet, ConfigError, CounterOverflow, Generator};
+
+/// Number of rounds of the Feistel network
+const ROUNDS: u64 = 8;
+
+/// A reversible mapping from sequence numbers to random looking ids
+///
+/// Each number from zero up to the number of possible ids is mapped to a different id of
+/// `size` characters from the alphabet, and the id can be decoded back into the number.
+/// This turns a sequential counter, such as a database primary key, into ids that don't
+/// reveal the order they were created in, or how many there are, without storing a mapping
+/// or generating any random data. Since the mapping is a permutation, the ids never collide.
+///
+/// The mapping is a keyed [Feistel network] over the binary representation of the number,
+/// with cycle walking to stay within the number of possible ids. The output for a given key,
+/// size, and alphabet is stable, and won't change without a breaking release.
+///
+/// **This is obfuscation, not encryption.** The key only has 64 bits, and the permutation
+/// hasn't been analyzed for security, so don't rely on it to keep the sequence secret from a
+/// determined attacker. It also doesn't protect against guessing valid ids, since every id of
+/// the right length and alphabet decodes to some number.
+///
+/// [Feistel network]: https://en.wikipedia.org/wiki/Feistel_cipher
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Obfuscator, alphabet::BASE58};
+///
+/// let obfuscator = Obfuscator::new(&BASE58, 8, 0x5ec2e7);
+/// let id = obfuscator.encode(42).unwrap();
+/// assert_eq!(id.len(), 8);
+/// assert_ne!(id, obfuscator.encode(43).unwrap());
+/// assert_eq!(obfuscator.decode(&id), Some(42));
+///
+/// // A different key gives a different mapping
+/// let other = Obfuscator::new(&BASE58, 8, 0xc0ffee);
+/// assert_ne!(other.encode(42).unwrap(), id);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Obfuscator<'a, const N: usize = 64> {
+    alphabet: &'a Alphabet<N>,
+    size: usize,
+    key: u64,
+    /// The number of possible ids, `N^size`
+    space: u128,
+    /// Number of bits in each half of the Feistel network
+    half_bits: u32,
+}
+
+impl<'a, const N: usize> Obfuscator<'a, N> {
+    /// Create a new obfuscator for ids of `size` characters from `alphabet`, using `key`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of possible ids doesn't fit in a `u128`. See
+    /// [`Obfuscator::try_new`].
+    #[track_caller]
+    pub fn new(alphabet: &'a Alphabet<N>, size: usize, key: u64) -> Self {
+        match Self::try_new(alphabet, size, key) {
+            Ok(obfuscator) => obfuscator,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Create a new obfuscator, or return an error if the size is too large
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::TooLarge`] if the number of possible ids, `N^size`, doesn't fit
+    /// in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{ConfigError, Obfuscator, alphabet::DEFAULT};
+    ///
+    /// assert!(Obfuscator::try_new(&DEFAULT, 21, 1).is_ok());
+    /// assert!(matches!(Obfuscator::try_new(&DEFAULT, 22, 1), Err(ConfigError::TooLarge)));
+    /// ```
+    pub fn try_new(alphabet: &'a Alphabet<N>, size: usize, key: u64) -> Result<Self, ConfigError> {
+        let space = Alphabet::<N>::fixed_width_capacity(size).ok_or(ConfigError::TooLarge)?;
+        let bits = u128::BITS - (space - 1).leading_zeros();
+        Ok(Obfuscator {
+            alphabet,
+            size,
+            key,
+            space,
+            half_bits: bits.div_ceil(2).max(1),
+        })
+    }
+
+    /// The number of possible ids
+    ///
+    /// [`Obfuscator::encode`] accepts any number less than this.
+    pub fn capacity(&self) -> u128 {
+        self.space
+    }
+
+    /// Map `value` to an id
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CounterOverflow`] if `value` isn't less than [`Obfuscator::capacity`].
+    pub fn encode(&self, value: u128) -> Result<String, CounterOverflow> {
+        if value >= self.space {
+            return Err(CounterOverflow);
+        }
+        // The Feistel network permutes a range that is a power of two, which can be bigger than
+        // the number of ids. Applying it again until the result is in range ("cycle walking")
+        // gives a permutation of just the ids.
+        let mut x = value;
+        loop {
+            x = self.permute(x);
+            if x < self.space {
+                break;
+            }
+        }
+        let mut res = String::with_capacity(self.size * self.alphabet.max_char_len());
+        self.alphabet
+            .write_u128_fixed(x, self.size, &mut res)
+            .unwrap();
+        Ok(res)
+    }
+
+    /// Map an id created with [`Obfuscator::encode`] back to the number it was created from
+    ///
+    /// Returns `None` if `id` doesn't have the configured size, or contains characters that
+    /// aren't in the alphabet.
+    pub fn decode(&self, id: &str) -> Option<u128> {
+        if id.chars().count() != self.size {
+            return None;
+        }
+        let mut x = if id.is_empty() {
+            0
+        } else {
+            self.alphabet.decode_u128(id)?
+        };
+        loop {
+            x = self.unpermute(x);
+            if x < self.space {
+                break;
+            }
+        }
+        Some(x)
+    }
+
+    fn half_mask(&self) -> u128 {
+        u128::MAX >> (u128::BITS - self.half_bits)
+    }
+
+    /// The round function of the Feistel network
+    fn round(&self, round: u64, half: u128) -> u128 {
+        // Each half has at most 64 bits.
+        let round_key = splitmix64(self.key ^ round.wrapping_mul(GOLDEN_GAMMA));
+        u128::from(splitmix64(half as u64 ^ round_key)) & self.half_mask()
+    }
+
+    fn permute(&self, x: u128) -> u128 {
+        let mut left = x >> self.half_bits;
+        let mut right = x & self.half_mask();
+        for round in 0..ROUNDS {
+            let next = left ^ self.round(round, right);
+            left = right;
+            right = next;
+        }
+        (left << self.half_bits) | right
+    }
+
+    fn unpermute(&self, x: u128) -> u128 {
+        let mut left = x >> self.half_bits;
+        let mut right = x & self.half_mask();
+        for round in (0..ROUNDS).rev() {
+            let prev = right ^ self.round(round, left);
+            right = left;
+            left = prev;
+        }
+        (left << self.half_bits) | right
+    }
+}
+
+impl<'a, R, const N: usize> Generator<'a, R, N> {
+    /// Map a sequence number to a random looking id, using `key`
+    ///
+    /// This is a shortcut for [`Obfuscator::encode`] with the alphabet and size of this
+    /// generator. The rng isn't used. To decode the ids, or to encode many of them, create an
+    /// [`Obfuscator`] with [`Generator::obfuscator`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CounterOverflow`] if `counter` isn't less than the number of possible ids.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of possible ids doesn't fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let gen = Generator::with_alphabet(&HEX).size(6);
+    /// let a = gen.gen_obfuscated_sequence(0x5ec2e7, 1).unwrap();
+    /// let b = gen.gen_obfuscated_sequence(0x5ec2e7, 2).unwrap();
+    /// assert_ne!(a, b);
+    /// assert_eq!(gen.obfuscator(0x5ec2e7).decode(&b), Some(2));
+    /// ```
+    #[track_caller]
+    pub fn gen_obfuscated_sequence(
+        &self,
+        key: u64,
+        counter: u128,
+    ) -> Result<String, CounterOverflow> {
+        self.obfuscator(key).encode(counter)
+    }
+
+    /// Create an [`Obfuscator`] with the alphabet and size of this generator
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of possible ids doesn't fit in a `u128`.
+    #[track_caller]
+    pub fn obfuscator(&self, key: u64) -> Obfuscator<'a, N> {
+        Obfuscator::new(self.alphabet, self.size, key)
+    }
+}
@@ -0,0 +1,130 @@
+//! Splitting an id into groups of characters.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::iter::FusedIterator;
+
+use rand::Rng;
+
+use crate::Generator;
+
+/// A new id, which can be iterated over in groups of characters
+///
+/// Created by [`Generator::parts`]. Iterating over a reference yields each group as a string
+/// slice, so the caller can put whatever separators it needs between them, without building
+/// the formatted string first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdParts {
+    id: String,
+    group_len: usize,
+}
+
+impl IdParts {
+    /// Iterate over the groups of characters in the id
+    ///
+    /// Every group has `group_len` characters, except the last, which has the rest of the id.
+    /// An empty id has no groups.
+    pub fn iter(&self) -> PartsIter<'_> {
+        PartsIter {
+            rest: &self.id,
+            group_len: self.group_len,
+        }
+    }
+
+    /// The whole id, without separators
+    pub fn as_str(&self) -> &str {
+        &self.id
+    }
+
+    /// Consume the parts, and return the whole id, without separators
+    pub fn into_string(self) -> String {
+        self.id
+    }
+}
+
+impl<'p> IntoIterator for &'p IdParts {
+    type Item = &'p str;
+    type IntoIter = PartsIter<'p>;
+
+    fn into_iter(self) -> PartsIter<'p> {
+        self.iter()
+    }
+}
+
+/// Iterator over the groups of characters of an id
+///
+/// Created by [`IdParts::iter`].
+#[derive(Debug, Clone)]
+pub struct PartsIter<'p> {
+    rest: &'p str,
+    group_len: usize,
+}
+
+impl<'p> Iterator for PartsIter<'p> {
+    type Item = &'p str;
+
+    fn next(&mut self) -> Option<&'p str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        // The alphabet can have multi-byte characters, so find where the group ends by
+        // counting characters, not bytes.
+        let end = self
+            .rest
+            .char_indices()
+            .nth(self.group_len)
+            .map_or(self.rest.len(), |(i, _)| i);
+        let (group, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(group)
+    }
+}
+
+impl FusedIterator for PartsIter<'_> {}
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Generate a new id, split into groups of `group_len` characters
+    ///
+    /// Every group has `group_len` characters, except the last, which can be shorter if the
+    /// size isn't a multiple of `group_len`. This is useful for writing an id with separators
+    /// to a stream, or for separators that change between groups. For a single separator
+    /// character, [`Generator::gen_license_key`] is simpler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group_len` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let parts = Generator::with_alphabet(&HEX).size(10).parts(4);
+    /// let groups: Vec<&str> = parts.iter().collect();
+    /// assert_eq!(groups.len(), 3);
+    /// assert_eq!(groups[0].len(), 4);
+    /// assert_eq!(groups[2].len(), 2);
+    ///
+    /// let mut formatted = String::new();
+    /// for (i, group) in parts.iter().enumerate() {
+    ///     if i > 0 {
+    ///         formatted.push(if i % 2 == 0 { '.' } else { '-' });
+    ///     }
+    ///     formatted.push_str(group);
+    /// }
+    /// assert_eq!(formatted.len(), 12);
+    /// assert_eq!(formatted.replace(['-', '.'], ""), parts.as_str());
+    /// ```
+    #[track_caller]
+    pub fn parts(&mut self, group_len: usize) -> IdParts {
+        assert!(
+            group_len > 0,
+            "the length of each group must be at least one"
+        );
+        IdParts {
+            id: self.gen(),
+            group_len,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Drawing the random data for an id ahead of time.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::convert::Infallible;
+
+use rand::Rng;
+
+use crate::{Alphabet, Generator};
+
+/// The random part of an id, which hasn't been turned into characters yet
+///
+/// Created by [`Generator::prepare`]. This holds the position in the alphabet of each
+/// character of the id, using one byte per character, so a prepared id of the default size
+/// takes 21 bytes, plus the overhead of a `Vec`.
+///
+/// Finishing a prepared id doesn't need the rng, and always produces the same id, so a
+/// prepared id should only be finished once, unless the same id is actually wanted.
+#[derive(Clone)]
+pub struct PreparedId<const N: usize = 64> {
+    indices: Vec<u8>,
+}
+
+impl<const N: usize> PreparedId<N> {
+    /// The number of characters in the id
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns true if the id has no characters
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Turn the prepared data into an id, using the characters from `alphabet`
+    pub fn finish(&self, alphabet: &Alphabet<N>) -> String {
+        let mut res = String::with_capacity(self.len() * alphabet.max_char_len());
+        res.extend(self.indices.iter().map(|&idx| alphabet.chars[idx as usize]));
+        res
+    }
+}
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Draw the random data for a new id, without turning it into characters yet
+    ///
+    /// The id can be created later with [`PreparedId::finish`], which doesn't need to draw any
+    /// random data. For example, a server can prepare ids while idle, so that the rng is
+    /// ready when a request arrives. The finished id is the same as the one [`Generator::gen`]
+    /// would have generated in place of `prepare`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let mut gen = Generator::with_alphabet(&HEX).size(12);
+    /// let prepared: Vec<_> = (0..10).map(|_| gen.prepare()).collect();
+    ///
+    /// for p in &prepared {
+    ///     let id = p.finish(&HEX);
+    ///     assert_eq!(id.len(), 12);
+    ///     assert!(HEX.validate(&id));
+    /// }
+    /// ```
+    pub fn prepare(&mut self) -> PreparedId<N> {
+        let mut indices = Vec::with_capacity(self.size);
+        self.for_each_index(self.size, |idx| {
+            // An alphabet has at most 255 characters, so every index fits in a byte.
+            indices.push(idx as u8);
+            Ok::<_, Infallible>(())
+        })
+        .unwrap_or_else(|e| match e {});
+        PreparedId { indices }
+    }
+}
@@ -0,0 +1,127 @@
+//! A generator that periodically reseeds its rng.
+#![cfg(feature = "std-rand")]
+
+use rand::rand_core::UnwrapErr;
+use rand::rngs::{OsRng, StdRng};
+use rand::{RngCore, SeedableRng, TryRngCore};
+
+use crate::alphabet::DEFAULT;
+use crate::{Alphabet, Generator, DEFAULT_SIZE};
+
+/// A generator that reseeds its rng after every `interval` ids
+///
+/// For generators that run for a very long time, periodically reseeding limits how many ids
+/// can be predicted if the state of the rng is ever compromised.
+///
+/// The rng is seeded from `seed_source` when the generator is created, and then again after
+/// every `interval` ids. By default, the rng is a [`StdRng`], seeded from [`OsRng`].
+///
+/// This is also an [`Iterator`] that generates new ids forever.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::ReseedingGenerator;
+///
+/// let mut gen = ReseedingGenerator::new(1000);
+/// let id = gen.gen();
+/// assert_eq!(id.len(), 21);
+/// assert_eq!(gen.take(5).count(), 5);
+/// ```
+///
+/// Using a custom seed source, that records the seeds it produces:
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use randoid::{ReseedingGenerator, alphabet::HEX, rngs::RecordingRng};
+///
+/// let source = RecordingRng::new(rand::rng());
+/// let mut gen = ReseedingGenerator::<StdRng, _, 16>::with_seed_source(8, &HEX, 3, source);
+/// for _ in 0..7 {
+///     gen.gen();
+/// }
+/// // Seeded when created, and before the 4th and 7th ids
+/// assert_eq!(gen.seed_source().recorded().len(), 3 * 32);
+/// ```
+pub struct ReseedingGenerator<'a, R = StdRng, S = UnwrapErr<OsRng>, const N: usize = 64> {
+    generator: Generator<'a, R, N>,
+    seed_source: S,
+    interval: usize,
+    remaining: usize,
+}
+
+impl ReseedingGenerator<'static> {
+    /// Create a generator using the default size and alphabet, that reseeds from the OS after
+    /// every `interval` ids
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    #[track_caller]
+    pub fn new(interval: usize) -> Self {
+        Self::with_seed_source(DEFAULT_SIZE, &DEFAULT, interval, OsRng.unwrap_err())
+    }
+}
+
+impl<'a, R: RngCore + SeedableRng, S: RngCore, const N: usize> ReseedingGenerator<'a, R, S, N> {
+    /// Create a generator, that reseeds from `seed_source` after every `interval` ids
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    #[track_caller]
+    pub fn with_seed_source(
+        size: usize,
+        alphabet: &'a Alphabet<N>,
+        interval: usize,
+        mut seed_source: S,
+    ) -> Self {
+        assert!(interval > 0, "reseed interval must be greater than zero");
+        let random = R::from_rng(&mut seed_source);
+        ReseedingGenerator {
+            generator: Generator::new(size, alphabet, random),
+            seed_source,
+            interval,
+            remaining: interval,
+        }
+    }
+
+    /// Generate a new random id, reseeding first if needed
+    pub fn gen(&mut self) -> String {
+        if self.remaining == 0 {
+            self.generator.random = R::from_rng(&mut self.seed_source);
+            self.remaining = self.interval;
+        }
+        self.remaining -= 1;
+        self.generator.gen()
+    }
+
+    /// The source of seeds for the rng
+    pub fn seed_source(&self) -> &S {
+        &self.seed_source
+    }
+
+    /// The number of ids generated between each reseed
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+}
+
+impl<R: RngCore + SeedableRng, S: RngCore, const N: usize> Iterator
+    for ReseedingGenerator<'_, R, S, N>
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        Some(self.gen())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: RngCore + SeedableRng, S: RngCore, const N: usize> core::iter::FusedIterator
+    for ReseedingGenerator<'_, R, S, N>
+{
+}
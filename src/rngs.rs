@@ -0,0 +1,314 @@
+//! Wrappers and implementations of [`RngCore`] that are useful with a [`Generator`](crate::Generator).
+//!
+//! These are mostly useful for testing and debugging.
+//!
+//! Rngs that can fail, such as [`SequenceRng`], only implement [`TryRngCore`], so their errors can
+//! be handled with [`Generator::try_write_to`](crate::Generator::try_write_to). To use one where an
+//! [`RngCore`] is needed, wrap it with [`TryRngCore::unwrap_err`], which panics on errors instead.
+
+use core::fmt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use rand::{RngCore, TryRngCore};
+
+/// An rng that records all the random data produced by another rng
+///
+/// Combined with [`SequenceRng`], this allows reproducing the exact ids a generator created,
+/// for example to debug a problem with a specific id.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, rngs::{RecordingRng, SequenceRng}};
+/// use rand::TryRngCore;
+///
+/// let mut gen = Generator::with_random(RecordingRng::new(rand::rng()));
+/// let ids = [gen.gen(), gen.gen()];
+///
+/// let log = gen.into_random().into_recorded();
+/// let mut replay = Generator::with_random(SequenceRng::new(&log).unwrap_err());
+/// assert_eq!(replay.gen(), ids[0]);
+/// assert_eq!(replay.gen(), ids[1]);
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone)]
+pub struct RecordingRng<R> {
+    inner: R,
+    recorded: Vec<u8>,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<R> RecordingRng<R> {
+    /// Create a new `RecordingRng` that records the output of `inner`
+    pub fn new(inner: R) -> Self {
+        RecordingRng {
+            inner,
+            recorded: Vec::new(),
+        }
+    }
+
+    /// All of the random data that has been produced so far
+    pub fn recorded(&self) -> &[u8] {
+        &self.recorded
+    }
+
+    /// Take the random data recorded so far, and start a new recording
+    pub fn take_recorded(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.recorded)
+    }
+
+    /// Consume the `RecordingRng`, and return the recorded data
+    pub fn into_recorded(self) -> Vec<u8> {
+        self.recorded
+    }
+
+    /// Consume the `RecordingRng`, and return the wrapped rng
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<R: TryRngCore> RecordingRng<R> {
+    /// Fill `dest` with random data from the wrapped rng, and record it
+    ///
+    /// This allows recording a fallible rng, which doesn't implement [`RngCore`]. Nothing is
+    /// recorded if the wrapped rng fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from the wrapped rng, if it fails.
+    pub fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), R::Error> {
+        self.inner.try_fill_bytes(dest)?;
+        self.recorded.extend_from_slice(dest);
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<R: RngCore> RngCore for RecordingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        let res = self.inner.next_u32();
+        self.recorded.extend_from_slice(&res.to_le_bytes());
+        res
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let res = self.inner.next_u64();
+        self.recorded.extend_from_slice(&res.to_le_bytes());
+        res
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.inner.fill_bytes(dest);
+        self.recorded.extend_from_slice(dest);
+    }
+}
+
+/// An rng that produces a fixed sequence of bytes
+///
+/// This is mostly useful for replaying data recorded with a [`RecordingRng`], or for tests.
+///
+/// `try_next_u32` and `try_next_u64` consume 4 and 8 bytes respectively, in little endian order.
+///
+/// This only implements [`TryRngCore`], and returns [`Exhausted`] if there isn't enough data
+/// left. Use [`TryRngCore::unwrap_err`] to panic instead.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::HEX, rngs::{Exhausted, SequenceRng}};
+/// use rand::TryRngCore;
+///
+/// let mut gen = Generator::new(4, &HEX, SequenceRng::new(&[0, 1, 0x1e, 0xff]).unwrap_err());
+/// assert_eq!(gen.gen(), "01ef");
+///
+/// let mut rng = SequenceRng::new(&[1, 2, 3]);
+/// assert_eq!(rng.try_fill_bytes(&mut [0; 2]), Ok(()));
+/// assert_eq!(rng.try_fill_bytes(&mut [0; 2]), Err(Exhausted));
+///
+/// let mut gen = Generator::new(4, &HEX, SequenceRng::new(&[0, 1]));
+/// let mut out = String::new();
+/// assert_eq!(gen.try_write_to(&mut out), Err(Exhausted));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceRng<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> SequenceRng<'a> {
+    /// Create a new rng that produces `bytes`, and then runs out
+    pub fn new(bytes: &'a [u8]) -> Self {
+        SequenceRng { bytes }
+    }
+
+    /// The data that hasn't been used yet
+    pub fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl TryRngCore for SequenceRng<'_> {
+    type Error = Exhausted;
+
+    fn try_next_u32(&mut self) -> Result<u32, Exhausted> {
+        let mut buf = [0; 4];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Exhausted> {
+        let mut buf = [0; 8];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    /// Fill `dest` with the next bytes of data
+    ///
+    /// If there isn't enough data left to fill `dest`, no data is consumed.
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Exhausted> {
+        if dest.len() > self.bytes.len() {
+            return Err(Exhausted);
+        }
+        let (head, tail) = self.bytes.split_at(dest.len());
+        dest.copy_from_slice(head);
+        self.bytes = tail;
+        Ok(())
+    }
+}
+
+/// Error returned when a [`SequenceRng`] doesn't have enough data left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exhausted;
+
+impl fmt::Display for Exhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ran out of random data")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Exhausted {}
+
+/// An rng that gets random data from a closure
+///
+/// The closure is called with a buffer to fill with random data. This is a quick way to use a
+/// custom source of randomness without implementing [`RngCore`].
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, alphabet::HEX, rngs::FnRng};
+///
+/// let mut next = 0u8;
+/// let rng = FnRng::new(|buf: &mut [u8]| {
+///     for b in buf {
+///         *b = next;
+///         next = next.wrapping_add(1);
+///     }
+/// });
+/// let mut gen = Generator::new(6, &HEX, rng);
+/// assert_eq!(gen.gen(), "012345");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FnRng<F> {
+    fill: F,
+}
+
+impl<F: FnMut(&mut [u8])> FnRng<F> {
+    /// Create a new rng that fills buffers using `fill`
+    pub fn new(fill: F) -> Self {
+        FnRng { fill }
+    }
+}
+
+impl<F: FnMut(&mut [u8])> RngCore for FnRng<F> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        (self.fill)(dest)
+    }
+}
+
+/// A cryptographically secure rng that reads from `/dev/urandom`
+///
+/// The file is opened once when the rng is created, and kept open for the lifetime of the rng,
+/// so it doesn't need to be opened again each time more random data is needed.
+///
+/// This doesn't depend on `getrandom`, so it can be used on unix systems with
+/// `default-features = false` to avoid that dependency.
+///
+/// This only implements [`TryRngCore`], and returns the [`io::Error`](std::io::Error) if
+/// reading from the file fails. Use [`TryRngCore::unwrap_err`] to panic instead.
+///
+/// # Examples
+///
+/// ```
+/// use randoid::{Generator, rngs::DevUrandomRng};
+/// use rand::TryRngCore;
+///
+/// let mut gen = Generator::with_random(DevUrandomRng::open().unwrap());
+/// let mut id = String::new();
+/// gen.try_write_to(&mut id).unwrap();
+/// assert_eq!(id.len(), 21);
+///
+/// let rng = DevUrandomRng::open().unwrap().unwrap_err();
+/// let id = Generator::with_random(rng).gen();
+/// assert_eq!(id.len(), 21);
+/// ```
+#[cfg(all(unix, feature = "unix"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "unix"))))]
+#[derive(Debug)]
+pub struct DevUrandomRng {
+    file: std::fs::File,
+}
+
+#[cfg(all(unix, feature = "unix"))]
+impl DevUrandomRng {
+    /// Open `/dev/urandom`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be opened.
+    pub fn open() -> std::io::Result<Self> {
+        let file = std::fs::File::open("/dev/urandom")?;
+        Ok(DevUrandomRng { file })
+    }
+}
+
+#[cfg(all(unix, feature = "unix"))]
+impl TryRngCore for DevUrandomRng {
+    type Error = std::io::Error;
+
+    fn try_next_u32(&mut self) -> std::io::Result<u32> {
+        let mut buf = [0; 4];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn try_next_u64(&mut self) -> std::io::Result<u64> {
+        let mut buf = [0; 8];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> std::io::Result<()> {
+        use std::io::Read;
+        self.file.read_exact(dest)
+    }
+}
+
+#[cfg(all(unix, feature = "unix"))]
+impl rand::TryCryptoRng for DevUrandomRng {}
@@ -1,38 +1,197 @@
 #![cfg(feature = "std-rand")]
 use super::Generator;
 
-use super::DEFAULT_SIZE;
-use crate::alphabet::{Alphabet, DEFAULT};
-use rand::{rngs::ThreadRng, thread_rng};
+use super::{Sampling, DEFAULT_SIZE};
+use crate::alphabet::{Alphabet, BASE62, DEFAULT};
+use rand::rngs::{SmallRng, ThreadRng};
+use rand::SeedableRng;
 
 impl<'a, const N: usize> Generator<'a, ThreadRng, N> {
     /// Create a new randoid generator using a specific alphabet
     ///
-    /// And using the default size and [`rand::thread_rng()`] as the RNG.
+    /// And using the default size and [`rand::rng()`] as the RNG.
     pub fn with_alphabet(alphabet: &'a Alphabet<N>) -> Self {
-        Self::new(DEFAULT_SIZE, alphabet, thread_rng())
+        Self::new(DEFAULT_SIZE, alphabet, rand::rng())
+    }
+
+    /// Create a new generator with a size big enough to avoid collisions
+    ///
+    /// The size is the smallest that keeps the probability of any two of `expected_ids`
+    /// ids being the same at or below `acceptable_prob`, as calculated by
+    /// [`size_for_collision_probability`](crate::size_for_collision_probability).
+    /// It uses `alphabet`, and [`rand::rng()`] as the RNG.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `acceptable_prob` isn't greater than zero, or the alphabet has fewer than
+    /// 2 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::{DEFAULT, HEX}};
+    ///
+    /// let id = Generator::for_collision_safety(1_000_000_000, 1e-9, &DEFAULT).gen();
+    /// assert_eq!(id.len(), 15);
+    ///
+    /// let id = Generator::for_collision_safety(1_000, 1e-6, &HEX).gen();
+    /// assert_eq!(id.len(), 10);
+    /// ```
+    #[track_caller]
+    pub fn for_collision_safety(
+        expected_ids: u64,
+        acceptable_prob: f64,
+        alphabet: &'a Alphabet<N>,
+    ) -> Self {
+        let size = crate::size_for_collision_probability(expected_ids, acceptable_prob, N);
+        Self::new(size, alphabet, rand::rng())
     }
 }
 
 impl<'a> Generator<'a, ThreadRng> {
     /// Create a new randoid generator that generates ids of a specific size
     ///
-    /// But use the default alphabet and [`rand::thread_rng()`] as the RNG.
+    /// But use the default alphabet and [`rand::rng()`] as the RNG.
     pub fn with_size(size: usize) -> Self {
         Self {
             alphabet: &DEFAULT,
-            random: thread_rng(),
+            random: rand::rng(),
             size,
+            sampling: Sampling::Bytes,
+            rejection_bits: None,
         }
     }
 }
 
+impl Generator<'static, ThreadRng> {
+    /// Create a new generator for ids of `size` characters, that are safe to use in urls
+    ///
+    /// This always uses the url safe [`DEFAULT`] alphabet, so ids never need to be
+    /// percent-encoded, and can be used in url paths, query strings, and fragments as is.
+    /// It uses [`rand::rng()`] as the RNG.
+    ///
+    /// This creates the same generator as [`Generator::with_size`], but makes it explicit that
+    /// the ids need to be url safe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let id = Generator::url_safe(32).gen();
+    /// assert_eq!(id.len(), 32);
+    /// // Only "unreserved" characters from RFC 3986, which never need to be percent-encoded
+    /// assert!(id
+    ///     .chars()
+    ///     .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~')));
+    /// ```
+    pub fn url_safe(size: usize) -> Self {
+        Self::new(size, &DEFAULT, rand::rng())
+    }
+}
+
+impl Generator<'static, ThreadRng, 62> {
+    /// Create a new generator for ids of `size` characters from the [`BASE62`] alphabet
+    ///
+    /// It uses [`rand::rng()`] as the RNG. Since 62 isn't a power of two, about 3% of the
+    /// random data is rejected to keep every character equally likely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let id = Generator::base62(10).gen();
+    /// assert_eq!(id.len(), 10);
+    /// assert!(id.chars().all(|c| matches!(c, '0'..='9' | 'A'..='Z' | 'a'..='z')));
+    /// ```
+    pub fn base62(size: usize) -> Self {
+        Self::new(size, &BASE62, rand::rng())
+    }
+}
+
+#[cfg(feature = "presets")]
+impl Generator<'static, ThreadRng, 32> {
+    /// Create a new generator for ids of `size` characters from the geohash
+    /// [`GEOHASH`](crate::alphabet::GEOHASH) alphabet
+    ///
+    /// It uses [`rand::rng()`] as the RNG. The ids look like geohash location codes, but are
+    /// completely random, so they don't correspond to any particular location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let id = Generator::geohash(9).gen();
+    /// assert_eq!(id.len(), 9);
+    /// assert!(!id.contains(['a', 'i', 'l', 'o']));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "presets")))]
+    pub fn geohash(size: usize) -> Self {
+        Self::new(size, &crate::alphabet::GEOHASH, rand::rng())
+    }
+}
+
+impl Generator<'static, SmallRng> {
+    /// Create a new randoid generator that uses a [`SmallRng`] seeded from the OS
+    ///
+    /// Using the default size and alphabet.
+    ///
+    /// `SmallRng` is fast, but is **not** cryptographically secure, so this should only be
+    /// used for ids that don't need to be unpredictable. For ids that need to be hard to
+    /// guess, use [`Generator::default()`] instead, which uses [`rand::rng()`], a
+    /// cryptographically secure RNG.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let mut gen = Generator::with_small_rng();
+    /// let id = gen.gen();
+    /// assert_eq!(id.len(), 21);
+    /// assert_ne!(id, gen.gen());
+    /// ```
+    pub fn with_small_rng() -> Self {
+        Self::with_random(SmallRng::from_os_rng())
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl Generator<'static, ThreadRng> {
+    /// Create a new randoid generator for WebAssembly running in a JavaScript environment
+    ///
+    /// Using the default size and alphabet, and [`rand::rng()`].
+    ///
+    /// On `wasm32-unknown-unknown`, the OS random source that seeds `rand::rng()` isn't
+    /// available unless it is told to use the JavaScript `crypto.getRandomValues` API, which the
+    /// `wasm` feature does. This is the same as [`Generator::default()`], but can only be called
+    /// with the feature enabled, so a missing feature is caught at compile time rather than
+    /// failing to link.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let id = Generator::with_wasm_rng().gen();
+    /// assert_eq!(id.len(), 21);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+    pub fn with_wasm_rng() -> Self {
+        Self::default()
+    }
+}
+
 impl Default for Generator<'static, rand::rngs::ThreadRng> {
     fn default() -> Self {
         Self {
             alphabet: &DEFAULT,
-            random: thread_rng(),
+            random: rand::rng(),
             size: DEFAULT_SIZE,
+            sampling: Sampling::Bytes,
+            rejection_bits: None,
         }
     }
 }
@@ -40,7 +199,7 @@ impl Default for Generator<'static, rand::rngs::ThreadRng> {
 /// Simple API for generating a nano id
 ///
 /// This creates a `String` containing a randomly generated id using the default size (21),
-/// alphabet (url safe with 64 characters), and Rng (`rand::thread_rng()`).
+/// alphabet (url safe with 64 characters), and Rng (`rand::rng()`).
 ///
 /// # Example:
 ///
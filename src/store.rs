@@ -0,0 +1,222 @@
+//! Checking generated ids against existing ids.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+use core::convert::Infallible;
+use core::fmt;
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::collections::{BTreeSet, HashSet};
+
+use rand::Rng;
+
+use crate::Generator;
+
+/// A collection of ids that are already in use
+///
+/// This allows [`Generator::gen_unique_in`] to be used with any storage backend,
+/// such as a database or a bloom filter.
+pub trait IdStore {
+    /// Returns true if `id` is already in use
+    fn contains(&self, id: &str) -> bool;
+}
+
+impl<T: IdStore + ?Sized> IdStore for &T {
+    fn contains(&self, id: &str) -> bool {
+        (**self).contains(id)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: std::hash::BuildHasher> IdStore for HashSet<String, S> {
+    fn contains(&self, id: &str) -> bool {
+        HashSet::contains(self, id)
+    }
+}
+
+impl IdStore for BTreeSet<String> {
+    fn contains(&self, id: &str) -> bool {
+        BTreeSet::contains(self, id)
+    }
+}
+
+/// Error returned when [`Generator::gen_unique_sorted`] or
+/// [`Generator::gen_k_sorted_unique_in_range`] can't find enough distinct ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotEnoughUnique {
+    /// The number of distinct ids that were found
+    pub found: usize,
+}
+
+impl fmt::Display for NotEnoughUnique {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "only found {} distinct ids", self.found)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotEnoughUnique {}
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Generate an id that isn't already in `store`
+    ///
+    /// Generates up to `max_tries` ids, and returns the first one that `store` doesn't contain.
+    /// Returns `None` if every attempt was already in use.
+    ///
+    /// Note that this doesn't add the id to `store`, that is up to the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use randoid::Generator;
+    ///
+    /// let mut used = HashSet::new();
+    /// let mut gen = Generator::with_size(8);
+    /// for _ in 0..10 {
+    ///     let id = gen.gen_unique_in(&used, 3).unwrap();
+    ///     assert!(used.insert(id));
+    /// }
+    /// ```
+    ///
+    /// Using a custom store:
+    ///
+    /// ```
+    /// use randoid::{Generator, IdStore};
+    ///
+    /// struct Everything;
+    ///
+    /// impl IdStore for Everything {
+    ///     fn contains(&self, _id: &str) -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Generator::default().gen_unique_in(&Everything, 5), None);
+    /// ```
+    pub fn gen_unique_in(&mut self, store: &impl IdStore, max_tries: usize) -> Option<String> {
+        (0..max_tries)
+            .map(|_| self.gen())
+            .find(|id| !store.contains(id))
+    }
+
+    /// Generate `n` distinct ids, in sorted order
+    ///
+    /// Generates up to `max_tries` ids in total, discarding any duplicates, until there are `n`
+    /// distinct ones. The ids are sorted in the usual string order, which is convenient for
+    /// test fixtures that need stable, distinct, ordered ids.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotEnoughUnique`] if there are fewer than `n` distinct ids after `max_tries`
+    /// attempts, which includes the case where `n` is more than the number of possible ids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, NotEnoughUnique, alphabet::HEX};
+    ///
+    /// let mut gen = Generator::with_alphabet(&HEX).size(4);
+    /// let ids = gen.gen_unique_sorted(100, 200).unwrap();
+    /// assert_eq!(ids.len(), 100);
+    /// assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    ///
+    /// // There are only 16 possible ids of one hex character
+    /// let mut gen = Generator::with_alphabet(&HEX).size(1);
+    /// assert_eq!(gen.gen_unique_sorted(17, 1000), Err(NotEnoughUnique { found: 16 }));
+    /// ```
+    pub fn gen_unique_sorted(
+        &mut self,
+        n: usize,
+        max_tries: usize,
+    ) -> Result<Vec<String>, NotEnoughUnique> {
+        let mut ids = BTreeSet::new();
+        for _ in 0..max_tries {
+            if ids.len() == n {
+                break;
+            }
+            ids.insert(self.gen());
+        }
+        if ids.len() < n {
+            return Err(NotEnoughUnique { found: ids.len() });
+        }
+        Ok(ids.into_iter().collect())
+    }
+
+    /// Generate `k` distinct ids that start with a character from part of the alphabet, in
+    /// sorted order
+    ///
+    /// The first character of each id is chosen uniformly from the characters at positions
+    /// `first` in the alphabet, and the rest of the id is generated as usual. If the alphabet is
+    /// in ascending order, such as [`HEX`](crate::alphabet::HEX), the ids all sort between the
+    /// first and last of those characters, so disjoint ranges give ids for separate partitions
+    /// that never overlap. Like [`Generator::gen_unique_sorted`], generates up to `max_tries` ids
+    /// in total, and returns them sorted in the usual string order.
+    ///
+    /// Restricting the first character reduces the number of possible ids to
+    /// `first.len() * N^(size - 1)`, so collisions are more likely than with
+    /// [`Generator::gen`], and a small range can run out of distinct ids entirely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotEnoughUnique`] if there are fewer than `k` distinct ids after `max_tries`
+    /// attempts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first` is empty or extends past the end of the alphabet, or if the size is
+    /// zero, since then there is no first character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, NotEnoughUnique, alphabet::HEX};
+    ///
+    /// let mut gen = Generator::with_alphabet(&HEX).size(6);
+    /// // Ids from "8" up to, but not including, "c"
+    /// let ids = gen.gen_k_sorted_unique_in_range(50, 8..12, 100).unwrap();
+    /// assert_eq!(ids.len(), 50);
+    /// assert!(ids.iter().all(|id| ("8".."c").contains(&id.as_str())));
+    /// assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    ///
+    /// // Only 2 * 16 ids of two characters start with "0" or "1"
+    /// let mut gen = Generator::with_alphabet(&HEX).size(2);
+    /// assert_eq!(
+    ///     gen.gen_k_sorted_unique_in_range(33, 0..2, 10_000),
+    ///     Err(NotEnoughUnique { found: 32 }),
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn gen_k_sorted_unique_in_range(
+        &mut self,
+        k: usize,
+        first: Range<usize>,
+        max_tries: usize,
+    ) -> Result<Vec<String>, NotEnoughUnique> {
+        assert!(
+            first.start < first.end && first.end <= N,
+            "the range of first characters must be a non-empty part of the alphabet"
+        );
+        assert!(self.size > 0, "the size must be at least one character");
+        let alphabet = self.alphabet;
+        let mut ids = BTreeSet::new();
+        for _ in 0..max_tries {
+            if ids.len() == k {
+                break;
+            }
+            let mut id = String::with_capacity(self.max_id_bytes());
+            id.push(alphabet.chars[self.random.random_range(first.clone())]);
+            self.for_each_index(self.size - 1, |idx| {
+                id.push(alphabet.chars[idx]);
+                Ok::<_, Infallible>(())
+            })
+            .unwrap_or_else(|e| match e {});
+            ids.insert(id);
+        }
+        if ids.len() < k {
+            return Err(NotEnoughUnique { found: ids.len() });
+        }
+        Ok(ids.into_iter().collect())
+    }
+}
@@ -0,0 +1,143 @@
+//! Ids with a keyed tag, which can be verified without storing them.
+#![cfg(feature = "blake3")]
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use rand::{Rng, RngCore};
+
+use crate::{Alphabet, Generator};
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Generate a new id, followed by a tag that proves it was generated with `key`
+    ///
+    /// The id has `size` random characters, followed by `tag_len` more characters derived from
+    /// a [BLAKE3] keyed hash of the random part. A server that issues ids this way can later
+    /// check that an id it receives is one of its own with [`Generator::verify_tag`], without
+    /// looking it up in a database. For example, to reject made up ids before doing any more
+    /// expensive work.
+    ///
+    /// # Security
+    ///
+    /// The key should be 32 random bytes, kept secret by the service, since anyone with the key
+    /// can create valid ids. Without the key, the best way to forge an id is to guess the tag,
+    /// which succeeds with a probability of `1 / N^tag_len` per guess. Each character of the tag
+    /// adds `log2(N)` bits, so for example 8 characters from [`BASE62`](crate::alphabet::BASE62)
+    /// give about 47 bits, which is plenty if guesses are rate limited, but 16 characters, or
+    /// about 95 bits, are better if an attacker can check guesses offline.
+    ///
+    /// The tag length should be fixed for the service, and passed to [`Generator::verify_tag`]
+    /// as well, so that a forger can't choose a shorter tag.
+    ///
+    /// The tag only proves that the id was issued with the key at some point. It doesn't
+    /// hide the random part, expire, or allow revoking individual ids. Changing the key
+    /// invalidates every id issued with the old one.
+    ///
+    /// [BLAKE3]: https://github.com/BLAKE3-team/BLAKE3
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::BASE62};
+    ///
+    /// let key = [0x42; 32];
+    /// let mut gen = Generator::with_alphabet(&BASE62).size(16);
+    /// let id = gen.gen_with_tag(&key, 8);
+    /// assert_eq!(id.len(), 24);
+    /// assert!(gen.verify_tag(&id, &key, 8));
+    /// assert!(!gen.verify_tag(&id, &[0x43; 32], 8));
+    /// ```
+    pub fn gen_with_tag(&mut self, key: &[u8; 32], tag_len: usize) -> String {
+        let mut res = self.gen();
+        let mut tag = tag_generator(self.alphabet, key, &res, tag_len);
+        tag.push_to(&mut res);
+        res
+    }
+
+    /// Check that `id` was created by [`Generator::gen_with_tag`] with `key` and `tag_len`
+    ///
+    /// The first `size` characters of `id` are the random part, and the rest is the tag, so
+    /// this must be called on a generator with the same size and alphabet as the one that
+    /// created the id. Returns false if the tag doesn't match, if it doesn't have exactly
+    /// `tag_len` characters, or if `id` has characters that aren't in the alphabet. An empty
+    /// tag never verifies.
+    ///
+    /// The tag length has to be given, rather than taken from `id`, because a shorter tag is
+    /// easier to guess. A tag of `n` characters usually starts with the tag of `n - 1`
+    /// characters for the same id, so otherwise a forger could just shorten the tag.
+    ///
+    /// See [`Generator::gen_with_tag`] for what the tag does and doesn't guarantee.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::{Generator, alphabet::HEX};
+    ///
+    /// let key = [7; 32];
+    /// let mut gen = Generator::with_alphabet(&HEX).size(12);
+    /// let id = gen.gen_with_tag(&key, 8);
+    /// assert!(gen.verify_tag(&id, &key, 8));
+    /// assert!(!gen.verify_tag(&id[..19], &key, 8));
+    ///
+    /// // Changing the random part invalidates the tag
+    /// let other = if id.starts_with('0') { "1" } else { "0" };
+    /// let tampered = format!("{other}{}", &id[1..]);
+    /// assert!(!gen.verify_tag(&tampered, &key, 8));
+    /// ```
+    pub fn verify_tag(&self, id: &str, key: &[u8; 32], tag_len: usize) -> bool {
+        let Some((split, _)) = id.char_indices().nth(self.size) else {
+            return false;
+        };
+        let (body, tag) = id.split_at(split);
+        if !self.alphabet.validate(body) || tag.chars().count() != tag_len {
+            return false;
+        }
+        let mut expected = String::with_capacity(tag.len());
+        tag_generator(self.alphabet, key, body, tag_len).push_to(&mut expected);
+        // Compare every byte, so the time taken doesn't reveal how much of the tag is right.
+        expected.len() == tag.len()
+            && expected
+                .bytes()
+                .zip(tag.bytes())
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+/// A generator for the tag of `body`, which uses the output of the keyed hash as its random
+/// data
+fn tag_generator<'a, const N: usize>(
+    alphabet: &'a Alphabet<N>,
+    key: &[u8; 32],
+    body: &str,
+    tag_len: usize,
+) -> Generator<'a, HashRng, N> {
+    let output = blake3::Hasher::new_keyed(key)
+        .update(body.as_bytes())
+        .finalize_xof();
+    Generator::new(tag_len, alphabet, HashRng(output))
+}
+
+/// Adapter to use the extendable output of BLAKE3 as an rng
+///
+/// Using the output as random data for a generator maps it to characters of the alphabet
+/// with the same uniform distribution as a random id.
+struct HashRng(blake3::OutputReader);
+
+impl RngCore for HashRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill(dest);
+    }
+}
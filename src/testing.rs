@@ -0,0 +1,112 @@
+//! Basic statistical tests for the quality of random data.
+//!
+//! These are simplified versions of the frequency (monobit) and runs tests from
+//! [NIST SP 800-22]. They can catch a badly broken source of random data, such as a custom
+//! [`RngCore`](rand::RngCore) implementation with a bug, but passing them is not evidence
+//! that the data is cryptographically secure.
+//!
+//! Each test returns a statistic that is approximately the absolute value of a standard
+//! normal variable for truly random input. Larger values mean the data is less likely to be
+//! random. [`THRESHOLD`] is a reasonable cutoff for failing a test.
+//!
+//! # Examples
+//!
+//! ```
+//! use rand::RngCore;
+//! use randoid::testing::{monobit, runs, THRESHOLD};
+//!
+//! let mut data = vec![0; 4096];
+//! rand::rng().fill_bytes(&mut data);
+//! assert!(monobit(&data) < THRESHOLD);
+//! assert!(runs(&data) < THRESHOLD);
+//! ```
+//!
+//! [NIST SP 800-22]: https://csrc.nist.gov/publications/detail/sp/800-22/rev-1a/final
+#![cfg(feature = "std")]
+
+/// Statistic above which data should be considered not random
+///
+/// For truly random data, a statistic is only larger than this with a probability of
+/// about 0.1%.
+pub const THRESHOLD: f64 = 3.29;
+
+/// Frequency (monobit) test
+///
+/// Checks that the number of one bits is close to the number of zero bits.
+///
+/// # Examples
+///
+/// ```
+/// use rand::{RngCore, SeedableRng};
+/// use randoid::testing::{monobit, THRESHOLD};
+///
+/// let mut data = [0; 1024];
+/// rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(1).fill_bytes(&mut data);
+/// assert!(monobit(&data) < THRESHOLD);
+///
+/// assert!(monobit(&[0; 1024]) > THRESHOLD);
+/// ```
+pub fn monobit(bytes: &[u8]) -> f64 {
+    let n = bit_len(bytes);
+    if n == 0.0 {
+        return 0.0;
+    }
+    let ones = count_ones(bytes);
+    let sum = 2.0 * ones - n;
+    sum.abs() / n.sqrt()
+}
+
+/// Runs test
+///
+/// Checks that the number of runs of consecutive identical bits is what would be expected,
+/// which detects data that changes between zero and one too quickly or too slowly.
+///
+/// If the data fails the [`monobit`] test badly enough that the runs test doesn't apply,
+/// this returns infinity.
+///
+/// # Examples
+///
+/// ```
+/// use rand::{RngCore, SeedableRng};
+/// use randoid::testing::{monobit, runs, THRESHOLD};
+///
+/// let mut data = [0; 1024];
+/// rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(1).fill_bytes(&mut data);
+/// assert!(runs(&data) < THRESHOLD);
+///
+/// // Alternating bits have as many ones as zeros, but far too many runs
+/// let alternating = [0b1010_1010; 1024];
+/// assert!(monobit(&alternating) < THRESHOLD);
+/// assert!(runs(&alternating) > THRESHOLD);
+/// ```
+pub fn runs(bytes: &[u8]) -> f64 {
+    let n = bit_len(bytes);
+    if n == 0.0 {
+        return 0.0;
+    }
+    let pi = count_ones(bytes) / n;
+    if (pi - 0.5).abs() >= 2.0 / n.sqrt() {
+        return f64::INFINITY;
+    }
+
+    let mut bits = bytes.iter().flat_map(|b| (0..8).map(move |i| (b >> i) & 1));
+    let mut prev = bits.next();
+    let mut runs = 1u64;
+    for bit in bits {
+        if Some(bit) != prev {
+            runs += 1;
+            prev = Some(bit);
+        }
+    }
+
+    let expected = 2.0 * n * pi * (1.0 - pi);
+    (runs as f64 - expected).abs() / (2.0 * (2.0 * n).sqrt() * pi * (1.0 - pi))
+}
+
+fn bit_len(bytes: &[u8]) -> f64 {
+    bytes.len() as f64 * 8.0
+}
+
+fn count_ones(bytes: &[u8]) -> f64 {
+    bytes.iter().map(|b| u64::from(b.count_ones())).sum::<u64>() as f64
+}
@@ -0,0 +1,78 @@
+//! Ids with the time they were created embedded in them.
+#![cfg(feature = "std")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+
+use crate::{Alphabet, Generator};
+
+/// Lowercase Crockford base32, used to encode the timestamp
+const TIME_ALPHABET: Alphabet<32> = Alphabet::new([
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'j',
+    'k', 'm', 'n', 'p', 'q', 'r', 's', 't', 'v', 'w', 'x', 'y', 'z',
+]);
+
+/// Number of characters in the timestamp suffix
+///
+/// Seven base32 digits is enough for timestamps until the year 3058.
+const TIME_LEN: usize = 7;
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Generate an id followed by a suffix encoding the current time
+    ///
+    /// The id consists of the usual random characters, followed by 7 characters that encode
+    /// the number of seconds since the unix epoch in lowercase Crockford base32. The time can
+    /// be extracted again with [`parse_timestamp`].
+    ///
+    /// This can be useful for debugging, but note that anyone who sees the id can tell
+    /// when it was created. Also, the suffix doesn't add any randomness to the id.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use randoid::{Generator, parse_timestamp};
+    ///
+    /// let id = Generator::default().gen_timestamped();
+    /// assert_eq!(id.len(), 21 + 7);
+    ///
+    /// let created = parse_timestamp(&id).unwrap();
+    /// let elapsed = SystemTime::now().duration_since(created).unwrap();
+    /// assert!(elapsed < Duration::from_secs(2));
+    /// ```
+    pub fn gen_timestamped(&mut self) -> String {
+        let mut res = String::with_capacity(self.max_id_bytes() + TIME_LEN);
+        self.write_to(&mut res).unwrap();
+        let mut secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let mut digits = [' '; TIME_LEN];
+        for d in digits.iter_mut().rev() {
+            *d = TIME_ALPHABET.chars[(secs % 32) as usize];
+            secs /= 32;
+        }
+        res.extend(digits);
+        res
+    }
+}
+
+/// Get the time an id created by [`Generator::gen_timestamped`] was created
+///
+/// The time only has a precision of one second. Returns `None` if `id` doesn't end with
+/// a valid timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time = randoid::parse_timestamp("V1StGXR8_Z5jdHi6B-myT1jn7w80").unwrap();
+/// assert_eq!(time, UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+/// assert_eq!(randoid::parse_timestamp("short"), None);
+/// ```
+pub fn parse_timestamp(id: &str) -> Option<SystemTime> {
+    let start = id.len().checked_sub(TIME_LEN)?;
+    let secs = TIME_ALPHABET.decode_u128(id.get(start..)?)?;
+    UNIX_EPOCH.checked_add(Duration::from_secs(secs as u64))
+}
@@ -0,0 +1,103 @@
+//! Generating UUIDs with the same rng as ids.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+use core::fmt;
+
+use rand::Rng;
+
+use crate::{alphabet::HEX, Generator};
+
+impl<'a, R: Rng, const N: usize> Generator<'a, R, N> {
+    /// Generate a random (version 4) UUID, using the generator's rng
+    ///
+    /// This draws 16 bytes of random data and sets the version and variant bits. The alphabet
+    /// and size of the generator aren't used. This allows using a single source of random data
+    /// for both ids and UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use randoid::Generator;
+    ///
+    /// let uuid = Generator::default().gen_uuid();
+    /// assert_eq!(uuid.get_version_num(), 4);
+    /// assert_eq!(uuid.get_variant(), uuid::Variant::RFC4122);
+    /// ```
+    #[cfg(feature = "uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+    pub fn gen_uuid(&mut self) -> ::uuid::Uuid {
+        ::uuid::Uuid::from_bytes(self.gen_uuid_bytes())
+    }
+
+    /// Generate a random (version 4) UUID, and write it to `out` as a hyphenated string
+    ///
+    /// The UUID is written in the canonical form of 32 lowercase hex digits in groups of
+    /// 8-4-4-4-12, for example `67e55044-10b1-426f-9247-bb680e5fe0c8`. Like
+    /// [`Generator::gen_uuid`], the alphabet and size of the generator aren't used, but this
+    /// doesn't need the `uuid` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut out = String::from("urn:uuid:");
+    /// randoid::Generator::default().write_hyphenated_uuid(&mut out).unwrap();
+    /// assert_eq!(out.len(), 9 + 36);
+    /// ```
+    pub fn write_hyphenated_uuid<W: fmt::Write + ?Sized>(&mut self, out: &mut W) -> fmt::Result {
+        for (i, b) in self.gen_uuid_bytes().into_iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                out.write_char('-')?;
+            }
+            out.write_char(HEX.chars[usize::from(b >> 4)])?;
+            out.write_char(HEX.chars[usize::from(b & 0xf)])?;
+        }
+        Ok(())
+    }
+
+    /// Generate a random (version 4) UUID, as a hyphenated string
+    ///
+    /// See [`Generator::write_hyphenated_uuid`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let uuid = randoid::Generator::default().gen_hyphenated_uuid_string();
+    /// assert_eq!(uuid.len(), 36);
+    /// assert_eq!(uuid.split('-').map(str::len).collect::<Vec<_>>(), [8, 4, 4, 4, 12]);
+    /// assert_eq!(&uuid[14..15], "4");
+    /// assert!(matches!(&uuid[19..20], "8" | "9" | "a" | "b"));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn gen_hyphenated_uuid_string(&mut self) -> String {
+        let mut res = String::with_capacity(36);
+        self.write_hyphenated_uuid(&mut res).unwrap();
+        res
+    }
+
+    /// Generate the 16 bytes of a random (version 4) UUID, using the generator's rng
+    ///
+    /// This draws 16 bytes of random data, and sets the version and variant bits, the same
+    /// way as [`Generator::gen_uuid`]. It is the lowest level way to generate a UUID, for
+    /// binary protocols that just need the bytes, and doesn't need the `uuid` feature or an
+    /// allocator. The alphabet and size of the generator aren't used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let bytes = randoid::Generator::default().gen_uuid_bytes();
+    /// // Version 4
+    /// assert_eq!(bytes[6] >> 4, 4);
+    /// // RFC 9562 variant
+    /// assert_eq!(bytes[8] >> 6, 0b10);
+    /// ```
+    pub fn gen_uuid_bytes(&mut self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        self.random.fill(&mut bytes);
+        // Version 4
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        // RFC 4122 (RFC 9562) variant
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        bytes
+    }
+}
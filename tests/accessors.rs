@@ -0,0 +1,14 @@
+//! Reading back the configuration of a generator.
+
+use randoid::{alphabet::HEX, rngs::SequenceRng, Generator, DEFAULT_SIZE};
+
+#[test]
+fn configuration_can_be_read_back() {
+    let gen = Generator::new(7, &HEX, SequenceRng::new(&[]));
+    assert_eq!(gen.current_size(), 7);
+    assert_eq!(gen.current_alphabet().len(), 16);
+    assert!(core::ptr::eq(gen.current_alphabet(), &HEX));
+
+    let gen = gen.size(DEFAULT_SIZE);
+    assert_eq!(gen.current_size(), DEFAULT_SIZE);
+}
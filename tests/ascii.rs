@@ -0,0 +1,34 @@
+//! Alphabets that are statically known to be ASCII.
+#![cfg(not(target_arch = "wasm32"))]
+
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{alphabet, AsciiAlphabet, Generator};
+
+#[test]
+fn presets_match_alphabets() {
+    assert_eq!(
+        AsciiAlphabet::DEFAULT.as_chars(),
+        alphabet::DEFAULT.as_chars()
+    );
+    assert_eq!(
+        AsciiAlphabet::HEX_UPPER.as_chars(),
+        alphabet::HEX_UPPER.as_chars()
+    );
+    assert_eq!(
+        AsciiAlphabet::BASE58.as_chars(),
+        alphabet::BASE58.as_chars()
+    );
+
+    let rng = Xoshiro256PlusPlus::seed_from_u64(425);
+    let mut gen = Generator::new_ascii(16, &AsciiAlphabet::HEX, rng);
+    let mut buf = [0; 16];
+    assert_eq!(gen.write_bytes(&mut buf), 16);
+    assert!(buf.iter().all(u8::is_ascii_hexdigit));
+}
+
+#[test]
+fn non_ascii_alphabet_has_no_byte_api() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/non_ascii_write_bytes.rs");
+}
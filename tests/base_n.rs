@@ -0,0 +1,28 @@
+//! Encoding numbers with an alphabet as the digits.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use randoid::{alphabet::HEX, Alphabet};
+
+#[test]
+fn round_trips_through_decode() {
+    let binary = Alphabet::new(['0', '1']);
+    for value in [0, 1, 2, 0xbeef, u128::MAX] {
+        assert_eq!(binary.decode_u128(&binary.encode_u128(value)), Some(value));
+        assert_eq!(HEX.decode_u128(&HEX.encode_u128(value)), Some(value));
+    }
+    assert_eq!(binary.encode_u128(u128::MAX).len(), 128);
+}
+
+#[test]
+#[should_panic(expected = "can't write a number in base 1")]
+fn base_1_is_rejected() {
+    let unary = Alphabet::new(['x']);
+    unary.encode_u128(3);
+}
+
+#[test]
+#[should_panic(expected = "can't write a number in base 1")]
+fn base_1_is_rejected_for_zero() {
+    let unary = Alphabet::new(['x']);
+    unary.encode_u128(0);
+}
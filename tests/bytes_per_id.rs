@@ -0,0 +1,51 @@
+//! Checking [`Generator::bytes_per_id`] against the random data actually drawn.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use rand::{SeedableRng, TryRngCore};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{
+    alphabet::BASE58,
+    rngs::{RecordingRng, SequenceRng},
+    Generator,
+};
+
+/// Sizes around the internal buffer size, so that ids which need more than one batch of random
+/// data are covered
+const SIZES: [usize; 8] = [1, 21, 57, 58, 64, 65, 100, 300];
+
+#[test]
+fn base58_draws_at_least_bytes_per_id() {
+    for size in SIZES {
+        for seed in 0..50 {
+            let rng = RecordingRng::new(Xoshiro256PlusPlus::seed_from_u64(seed));
+            let mut gen = Generator::new(size, &BASE58, rng);
+            let expected = gen.bytes_per_id();
+            gen.gen();
+            let drawn = gen.into_random().into_recorded().len();
+            assert!(
+                drawn >= expected,
+                "size {size}, seed {seed}: drew {drawn} bytes, bytes_per_id is {expected}"
+            );
+        }
+    }
+}
+
+#[test]
+fn base58_draws_bytes_per_id_when_nothing_is_rejected() {
+    // Every byte is a valid index, so none are discarded.
+    let bytes: Vec<u8> = (0..58).cycle().take(1000).collect();
+    for size in SIZES {
+        let mut gen = Generator::new(
+            size,
+            &BASE58,
+            RecordingRng::new(SequenceRng::new(&bytes).unwrap_err()),
+        );
+        let expected = gen.bytes_per_id();
+        gen.gen();
+        assert_eq!(
+            gen.into_random().into_recorded().len(),
+            expected,
+            "size {size}"
+        );
+    }
+}
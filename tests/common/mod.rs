@@ -0,0 +1,22 @@
+//! Fixtures shared by the integration tests.
+
+use rand::{rand_core::UnwrapErr, TryRngCore};
+use randoid::rngs::SequenceRng;
+
+/// Every byte value, in increasing order
+static COUNTING_BYTES: [u8; 256] = {
+    let mut bytes = [0; 256];
+    let mut i = 0;
+    while i < bytes.len() {
+        bytes[i] = i as u8;
+        i += 1;
+    }
+    bytes
+};
+
+/// An rng that produces every byte value once, in increasing order, and then panics
+///
+/// This makes every index of a small alphabet come up, in a predictable order.
+pub fn counting_rng() -> UnwrapErr<SequenceRng<'static>> {
+    SequenceRng::new(&COUNTING_BYTES).unwrap_err()
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "serde")]
+
+use randoid::{alphabet::Preset, Config, Generator};
+
+#[test]
+fn load_config_from_json() {
+    let config: Config = serde_json::from_str(r#"{"size": 10, "alphabet": "hex-upper"}"#).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            size: 10,
+            alphabet: Preset::HexUpper
+        }
+    );
+    let id = Generator::<_, 16>::from_config(&config, rand::rng())
+        .unwrap()
+        .gen();
+    assert_eq!(id.len(), 10);
+    assert!(id.chars().all(|c| matches!(c, '0'..='9' | 'A'..='F')));
+}
+
+#[test]
+fn build_generator_from_json() {
+    let config: Config = serde_json::from_str(r#"{"size": 16, "alphabet": "hex"}"#).unwrap();
+    let mut gen = Generator::<_, 16>::from_config(&config, rand::rng()).unwrap();
+    let id = gen.gen();
+    assert_eq!(id.len(), 16);
+    assert!(id
+        .chars()
+        .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+}
+
+#[test]
+fn invalid_config_is_rejected() {
+    let err = serde_json::from_str::<Config>(r#"{"size": 0}"#).unwrap_err();
+    assert!(err.to_string().contains("greater than zero"), "{err}");
+    assert!(serde_json::from_str::<Config>(r#"{"alphabet": "base64"}"#).is_err());
+    assert!(serde_json::from_str::<Config>(r#"{"size": -1}"#).is_err());
+}
+
+#[test]
+fn missing_fields_use_defaults() {
+    let config: Config = serde_json::from_str("{}").unwrap();
+    assert_eq!(config, Config::default());
+}
+
+#[test]
+fn config_round_trips_through_json() {
+    let config = Config {
+        size: 32,
+        alphabet: Preset::NanoidCompat,
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"size":32,"alphabet":"nanoid"}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+}
+
+#[test]
+fn generator_config_round_trips() {
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    let rng = || Xoshiro256PlusPlus::seed_from_u64(468);
+    let config = Config {
+        size: 14,
+        alphabet: Preset::Base58,
+    };
+    let mut original = Generator::<_, 58>::from_config(&config, rng()).unwrap();
+    let snapshot = serde_json::to_string(&original.config().unwrap()).unwrap();
+    let restored: Config = serde_json::from_str(&snapshot).unwrap();
+    assert_eq!(restored, config);
+
+    let mut rebuilt = Generator::<_, 58>::from_config(&restored, rng()).unwrap();
+    for _ in 0..10 {
+        assert_eq!(original.gen(), rebuilt.gen());
+    }
+}
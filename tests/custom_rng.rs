@@ -0,0 +1,121 @@
+//! Using a generator without the `std-rand` feature.
+//!
+//! This should pass with `--no-default-features --features alloc`.
+
+use rand::TryRngCore;
+use randoid::{
+    alphabet::HEX,
+    rngs::{Exhausted, RecordingRng, SequenceRng},
+    Generator, DEFAULT_SIZE,
+};
+
+#[test]
+fn default_config_with_custom_rng() {
+    let bytes = [7u8; DEFAULT_SIZE];
+    let mut gen = Generator::with_random(SequenceRng::new(&bytes).unwrap_err());
+    assert_eq!(gen.gen(), "5".repeat(DEFAULT_SIZE));
+}
+
+#[test]
+fn custom_config_with_custom_rng() {
+    let bytes: Vec<u8> = (0..16).collect();
+    let mut gen = Generator::new(16, &HEX, SequenceRng::new(&bytes).unwrap_err());
+    assert_eq!(gen.gen(), "0123456789abcdef");
+}
+
+/// With rand 0.9, fallible rngs such as `OsRng` only implement `TryRngCore`, and need to be
+/// wrapped to panic on errors.
+#[cfg(feature = "std-rand")]
+#[test]
+fn fallible_rng() {
+    use rand::TryRngCore;
+
+    let mut gen = Generator::with_random(rand::rngs::OsRng.unwrap_err());
+    assert_eq!(gen.gen().len(), DEFAULT_SIZE);
+}
+
+/// Fails on the second call to fill a buffer.
+struct FailsOnRefill(bool);
+
+#[derive(Debug, PartialEq)]
+struct Failed;
+
+impl core::fmt::Display for Failed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("failed")
+    }
+}
+
+impl rand::TryRngCore for FailsOnRefill {
+    type Error = Failed;
+
+    fn try_next_u32(&mut self) -> Result<u32, Failed> {
+        let mut buf = [0; 4];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Failed> {
+        let mut buf = [0; 8];
+        self.try_fill_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Failed> {
+        if self.0 {
+            return Err(Failed);
+        }
+        self.0 = true;
+        dest.fill(0xab);
+        Ok(())
+    }
+}
+
+#[test]
+fn failed_write_is_rolled_back() {
+    let mut out = String::from("prefix:");
+    let mut gen = Generator::new(100, &HEX, FailsOnRefill(false));
+    assert_eq!(gen.try_write_to(&mut out), Err(Failed));
+    assert_eq!(out, "prefix:");
+
+    let mut gen = Generator::new(10, &HEX, FailsOnRefill(false));
+    assert_eq!(gen.try_write_to(&mut out), Ok(()));
+    assert_eq!(out, "prefix:bbbbbbbbbb");
+}
+
+#[test]
+fn exhausted_sequence_is_returned() {
+    let bytes = [0u8; 10];
+    let mut out = String::from("prefix:");
+    let mut gen = Generator::new(16, &HEX, SequenceRng::new(&bytes));
+    assert_eq!(gen.try_write_to(&mut out), Err(Exhausted));
+    assert_eq!(out, "prefix:");
+
+    let mut gen = Generator::new(10, &HEX, SequenceRng::new(&bytes));
+    assert_eq!(gen.try_write_to(&mut out), Ok(()));
+    assert_eq!(out, "prefix:0000000000");
+}
+
+#[test]
+fn recording_fallible_rng() {
+    let bytes = [1, 2, 3];
+    let mut rng = RecordingRng::new(SequenceRng::new(&bytes));
+    assert_eq!(rng.try_fill_bytes(&mut [0; 2]), Ok(()));
+    assert_eq!(rng.try_fill_bytes(&mut [0; 2]), Err(Exhausted));
+    assert_eq!(rng.recorded(), [1, 2]);
+}
+
+#[test]
+fn id_iterator_is_fused() {
+    fn assert_fused<I: core::iter::FusedIterator>(iter: I) -> I {
+        iter
+    }
+
+    let bytes = [0u8; 8 * 100];
+    let ids =
+        assert_fused(Generator::new(8, &HEX, SequenceRng::new(&bytes).unwrap_err()).into_iter());
+    assert_eq!(ids.size_hint(), (usize::MAX, None));
+    let ids: Vec<String> = ids.take(100).collect();
+    assert_eq!(ids.len(), 100);
+    assert!(ids.iter().all(|id| id == "00000000"));
+}
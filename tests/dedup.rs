@@ -0,0 +1,54 @@
+//! Avoiding collisions with recent ids.
+#![cfg(feature = "dedup")]
+
+use rand::TryRngCore;
+use randoid::{
+    alphabet::{Alphabet, HEX},
+    rngs::SequenceRng,
+    DedupGenerator, Generator,
+};
+
+#[test]
+fn collision_is_regenerated() {
+    let bytes = [1, 1, 1, 2];
+    let mut gen = DedupGenerator::new(
+        Generator::new(1, &HEX, SequenceRng::new(&bytes).unwrap_err()),
+        4,
+    );
+    assert_eq!(gen.gen(), "1");
+    assert_eq!(gen.gen(), "2");
+    assert!(gen.contains("1"));
+    assert!(gen.contains("2"));
+}
+
+#[test]
+fn old_ids_are_forgotten() {
+    let bytes = [1, 2, 1];
+    let mut gen = DedupGenerator::new(
+        Generator::new(1, &HEX, SequenceRng::new(&bytes).unwrap_err()),
+        1,
+    );
+    assert_eq!(gen.gen(), "1");
+    assert_eq!(gen.gen(), "2");
+    assert!(!gen.contains("1"));
+    assert_eq!(gen.gen(), "1");
+}
+
+#[test]
+fn unique_within_window() {
+    let alphabet = Alphabet::new(['a', 'b']);
+    let mut gen = DedupGenerator::new(Generator::with_alphabet(&alphabet).size(2), 3);
+    let ids: Vec<String> = (0..1000).map(|_| gen.gen()).collect();
+    for window in ids.windows(4) {
+        for (i, id) in window.iter().enumerate() {
+            assert!(!window[i + 1..].contains(id), "repeated id in {:?}", window);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn capacity_must_be_less_than_id_space() {
+    let alphabet = Alphabet::new(['a', 'b']);
+    DedupGenerator::new(Generator::with_alphabet(&alphabet).size(2), 4);
+}
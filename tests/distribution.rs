@@ -0,0 +1,93 @@
+//! Rough checks that generated characters are uniformly distributed.
+//!
+//! These use a fixed seed, so they are deterministic, but the tolerances are loose
+//! enough that they would pass for any reasonable seed.
+
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{
+    alphabet::{BASE58, HEX},
+    Alphabet, Generator, Sampling,
+};
+
+const SAMPLES: usize = 64_000;
+
+/// Assert that every character of `chars` appears in `s` with roughly equal frequency
+fn assert_uniform(s: &str, chars: &[char]) {
+    let expected = s.chars().count() / chars.len();
+    for &c in chars {
+        let count = s.chars().filter(|&x| x == c).count();
+        assert!(
+            count.abs_diff(expected) < expected / 10,
+            "{c:?} appeared {count} times, expected about {expected}"
+        );
+    }
+}
+
+fn rng() -> Xoshiro256PlusPlus {
+    Xoshiro256PlusPlus::seed_from_u64(0x5eed)
+}
+
+#[test]
+fn hex_alphabet_is_uniform() {
+    let id = Generator::new(SAMPLES, &HEX, rng()).gen();
+    assert_uniform(&id, HEX.as_chars());
+}
+
+#[test]
+fn hex_fast_matches_hex_alphabet_distribution() {
+    let fast = Generator::with_random(rng()).hex_fast(SAMPLES);
+    assert_eq!(fast.len(), SAMPLES);
+    assert!(HEX.validate(&fast));
+    assert_uniform(&fast, HEX.as_chars());
+}
+
+#[test]
+fn non_power_of_two_alphabet_is_uniform() {
+    // With a mask of 0b11111, values from 26 to 31 have to be rejected. Using a modulus
+    // instead would make the first 6 letters twice as likely as the others.
+    let letters = Alphabet::<26>::from_range('a', 'z');
+    let id = Generator::new(SAMPLES, &letters, rng()).gen();
+    assert_eq!(id.len(), SAMPLES);
+    assert_uniform(&id, letters.as_chars());
+}
+
+#[test]
+fn alphabet_just_over_power_of_two_is_uniform() {
+    let chars: [char; 17] = randoid::alphabet::char_range('a', 'q');
+    let alphabet = Alphabet::new(chars);
+    let id = Generator::new(SAMPLES, &alphabet, rng()).gen();
+    assert_eq!(id.len(), SAMPLES);
+    assert_uniform(&id, alphabet.as_chars());
+}
+
+#[test]
+fn uniform_sampling_is_uniform() {
+    let id = Generator::new(SAMPLES, &BASE58, rng())
+        .sampling(Sampling::Uniform)
+        .gen();
+    assert_eq!(id.len(), SAMPLES);
+    assert!(BASE58.validate(&id));
+    assert_uniform(&id, BASE58.as_chars());
+}
+
+#[test]
+fn wide_rejection_mask_is_uniform() {
+    let chars: [char; 33] = randoid::alphabet::char_range('!', 'A');
+    let alphabet = Alphabet::new(chars);
+    let id = Generator::new(SAMPLES, &alphabet, rng())
+        .rejection_bits(8)
+        .gen();
+    assert_eq!(id.chars().count(), SAMPLES);
+    assert!(alphabet.validate(&id));
+    assert_uniform(&id, alphabet.as_chars());
+
+    // Every possible bit width stays in range
+    for bits in 0..=8 {
+        let id = Generator::new(1000, &BASE58, rng())
+            .rejection_bits(bits)
+            .gen();
+        assert_eq!(id.len(), 1000);
+        assert!(BASE58.validate(&id));
+    }
+}
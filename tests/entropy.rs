@@ -0,0 +1,33 @@
+//! The collision probability helpers.
+//!
+//! This should also pass with `--no-default-features --features libm`.
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use randoid::{collision_probability, entropy_bits, size_for_collision_probability};
+
+#[test]
+fn entropy_of_default_id() {
+    assert_eq!(entropy_bits(21, 64), 126.0);
+}
+
+#[test]
+fn size_keeps_probability_below_target() {
+    for (ids, prob, alphabet_len) in [
+        (1_000_000_000, 1e-9, 64),
+        (1_000, 1e-6, 16),
+        (10_000_000, 0.01, 58),
+        (2, 0.5, 2),
+    ] {
+        let size = size_for_collision_probability(ids, prob, alphabet_len);
+        assert!(collision_probability(ids, size, alphabet_len) <= prob);
+        if size > 1 {
+            assert!(collision_probability(ids, size - 1, alphabet_len) > prob);
+        }
+    }
+}
+
+#[test]
+fn few_ids_need_one_character() {
+    assert_eq!(size_for_collision_probability(1, 1e-9, 64), 1);
+    assert_eq!(size_for_collision_probability(0, 1e-9, 64), 1);
+}
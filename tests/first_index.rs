@@ -0,0 +1,19 @@
+//! Reporting the index of the first character of an id.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+mod common;
+
+use randoid::{alphabet::HEX, Generator};
+
+#[test]
+fn first_index_matches_first_character() {
+    let mut gen = Generator::new(4, &HEX, common::counting_rng());
+    for _ in 0..64 {
+        let (id, first) = gen.gen_with_first_index();
+        let first = first.unwrap();
+        assert_eq!(
+            HEX.as_chars().iter().position(|&c| id.starts_with(c)),
+            Some(first)
+        );
+    }
+}
@@ -0,0 +1,37 @@
+//! Generating ids that fill an exact number of bytes.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+mod common;
+
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{alphabet::HEX, Alphabet, Generator, DEFAULT_SIZE};
+
+#[test]
+fn fixed_bytes_ascii_is_exact() {
+    let mut gen = Generator::new(DEFAULT_SIZE, &HEX, common::counting_rng());
+    for total in 0..20 {
+        let id = gen.gen_fixed_bytes(total);
+        assert_eq!(id.len(), total);
+        assert!(HEX.validate(&id));
+    }
+}
+
+#[test]
+fn fixed_bytes_multi_byte_never_overshoots() {
+    let mixed = Alphabet::new(['a', 'é', '€', '🦀']);
+    let accents = Alphabet::new(['é', 'è', 'ê', 'ë']);
+    let rng = Xoshiro256PlusPlus::seed_from_u64(470);
+    let mut mixed_gen = Generator::new(DEFAULT_SIZE, &mixed, rng.clone());
+    let mut accents_gen = Generator::new(DEFAULT_SIZE, &accents, rng);
+    for total in 0..100 {
+        // The alphabet has a one byte character, so any length can be filled exactly
+        let id = mixed_gen.gen_fixed_bytes(total);
+        assert_eq!(id.len(), total);
+        assert!(mixed.validate(&id));
+
+        let id = accents_gen.gen_fixed_bytes(total);
+        assert_eq!(id.len(), total / 2 * 2);
+        assert!(accents.validate(&id));
+    }
+}
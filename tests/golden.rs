@@ -0,0 +1,109 @@
+//! Golden tests for the exact ids generated from a seeded rng.
+//!
+//! # Stability policy
+//!
+//! For a given sequence of random bytes, alphabet, and size, the generated id is part of the
+//! crate's public behavior. Downstream crates may rely on seeded generators producing the ids
+//! listed here in their own tests.
+//!
+//! These values must not change in a patch or minor release. If the sampling algorithm
+//! changes in a way that changes them (for example, how random bytes are mapped to
+//! characters), that is a breaking change: update the table, and release a new major
+//! version (or minor version while the crate is still `0.x`), noting the change in the
+//! changelog.
+//!
+//! `golden_bytes` uses fixed random bytes, so it only depends on this crate. `golden_ids` also
+//! depends on the output of `rand_xoshiro`, which is itself stable. `golden_keyed` covers
+//! `gen_from_key`, whose hash and rng are also part of its stable output, and
+//! `golden_obfuscated` covers `Obfuscator`, whose permutation is too.
+
+use rand::{SeedableRng, TryRngCore};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{alphabet, gen_from_key, rngs::SequenceRng, Alphabet, Generator, Obfuscator};
+
+fn gen<const N: usize>(seed: u64, alphabet: &Alphabet<N>, size: usize) -> String {
+    Generator::new(size, alphabet, Xoshiro256PlusPlus::seed_from_u64(seed)).gen()
+}
+
+#[test]
+fn golden_ids() {
+    let cases = [
+        (gen(0, &alphabet::DEFAULT, 21), "tx97vrlh5j_1XJovWVoGf"),
+        (gen(1, &alphabet::DEFAULT, 21), "p01JZe3dbuZkM-0ZuPEll"),
+        (gen(42, &alphabet::DEFAULT, 100), "tCQ2dbQefRJlO-sfaHyaVWuVSPmautXNNE2OSqx9Xsec_tbkkPowZmlu49mc5cDo5ajKoNGPiuOnMkJI0Tc0mUXdbQJT_cpnKBiI"),
+        (gen(0, &alphabet::HEX, 32), "f3b91d737503dfa1cbac1ffcaeebcfe2"),
+        (gen(7, &alphabet::HEX_UPPER, 16), "D1EA0ACE4EEAD8FC"),
+        (gen(0, &alphabet::NANOID_COMPAT, 21), "YDT2IExXm5uaiZCIrzC_0"),
+        (gen(0, &alphabet::BASE58, 22), "YcCAaWQL8N14pTaTmJYGVD"),
+        (gen(3, &alphabet::BASE58, 70), "iiSzJkkEdiqoYmWeYech3buWxpeNy87R7jQPXwvZkyGKK6xVz5q3ZmZoQhJcVZYcuwPrj6"),
+        (gen(0, &alphabet::ASCII_PRINTABLE_SAFE, 20), "F.n:x*z!hb=42=c_/oRh"),
+    ];
+    for (actual, expected) in cases {
+        assert_eq!(actual, expected);
+    }
+}
+
+fn gen_bytes<const N: usize>(alphabet: &Alphabet<N>, size: usize) -> String {
+    let bytes: Vec<u8> = (0..=255u8)
+        .cycle()
+        .take(1024)
+        .map(|b| b.wrapping_mul(167))
+        .collect();
+    Generator::new(size, alphabet, SequenceRng::new(&bytes).unwrap_err()).gen()
+}
+
+#[test]
+fn golden_bytes() {
+    let cases = [
+        (gen_bytes(&alphabet::DEFAULT, 21), "_BcPq1EfSt4HiVw7KlYza"),
+        (gen_bytes(&alphabet::HEX, 16), "07e5c3a18f6d4b29"),
+        (gen_bytes(&alphabet::BASE58, 21), "1gFvV4jJyY7nMbAqQeDtT"),
+        (
+            gen_bytes(&alphabet::ASCII_PRINTABLE_SAFE, 21),
+            "!Js?h4[)PyEn:c/V#Kt@i",
+        ),
+    ];
+    for (actual, expected) in cases {
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn golden_keyed() {
+    let cases = [
+        (
+            gen_from_key(b"", 21, &alphabet::DEFAULT),
+            "KZyF-W-1khW_GoaeppQGD",
+        ),
+        (
+            gen_from_key(b"user@example.com", 21, &alphabet::DEFAULT),
+            "9d2CU8VsRDaiGaT6e38s3",
+        ),
+        (
+            gen_from_key(b"user@example.com", 16, &alphabet::HEX),
+            "bf48aabe79c4cc98",
+        ),
+        (
+            gen_from_key(b"user@example.com", 22, &alphabet::BASE58),
+            "CG5hBXxiDMmDz9H6BX65nC",
+        ),
+    ];
+    for (actual, expected) in cases {
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn golden_obfuscated() {
+    let default = Obfuscator::new(&alphabet::DEFAULT, 21, 0);
+    let base58 = Obfuscator::new(&alphabet::BASE58, 8, 0x5eed);
+    let cases = [
+        (default.encode(0).unwrap(), "h9LaArD2U6CyTZ0s9Q5p-"),
+        (default.encode(1).unwrap(), "lpwsyCnZljVd7ncr7zSsw"),
+        (base58.encode(0).unwrap(), "sbLbsSw5"),
+        (base58.encode(12345).unwrap(), "M3LrK6gd"),
+    ];
+    for (actual, expected) in cases {
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,73 @@
+//! Writing ids with `std::io`.
+#![cfg(feature = "std")]
+
+use std::io::{self, Write};
+
+use rand::TryRngCore;
+use randoid::{alphabet::HEX, rngs::SequenceRng, Generator};
+
+/// A writer that records how much had been written each time it was flushed
+#[derive(Default)]
+struct FlushCounter {
+    written: Vec<u8>,
+    flushed_at: Vec<usize>,
+}
+
+impl Write for FlushCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.written.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flushed_at.push(self.written.len());
+        Ok(())
+    }
+}
+
+fn flushes(count: usize, flush_every: usize) -> Vec<usize> {
+    let bytes = [0; 64];
+    let mut gen = Generator::new(4, &HEX, SequenceRng::new(&bytes).unwrap_err());
+    let mut out = FlushCounter::default();
+    gen.write_batch_flushing(&mut out, count, b',', flush_every)
+        .unwrap();
+    assert_eq!(out.written.len(), (count * 5).saturating_sub(1));
+    out.flushed_at
+}
+
+#[test]
+fn flushes_every_k_ids() {
+    // Each id is 4 bytes, followed by a separator
+    assert_eq!(flushes(10, 3), [14, 29, 44, 49]);
+    assert_eq!(flushes(9, 3), [14, 29, 44]);
+    assert_eq!(flushes(3, 1), [4, 9, 14]);
+    assert_eq!(flushes(2, 5), [9]);
+}
+
+#[test]
+fn flushes_only_at_end_without_interval() {
+    assert_eq!(flushes(10, 0), [49]);
+    assert_eq!(flushes(0, 0), [0]);
+    assert_eq!(flushes(0, 3), [0]);
+}
+
+#[test]
+fn flush_errors_are_returned() {
+    struct FailingFlush;
+
+    impl Write for FailingFlush {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::Error::other("disk full"))
+        }
+    }
+
+    let bytes = [0; 64];
+    let err = Generator::new(4, &HEX, SequenceRng::new(&bytes).unwrap_err())
+        .write_batch_flushing(&mut FailingFlush, 10, b'\n', 2)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "disk full");
+}
@@ -0,0 +1,74 @@
+//! Reversible mapping from sequence numbers to ids.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use std::collections::HashSet;
+
+use randoid::{alphabet, rngs::SequenceRng, Alphabet, CounterOverflow, Generator, Obfuscator};
+
+/// Check that `obfuscator` maps every possible value to a different id, and back
+fn check_bijection<const N: usize>(obfuscator: &Obfuscator<'_, N>, alphabet: &Alphabet<N>) {
+    let mut seen = HashSet::new();
+    for value in 0..obfuscator.capacity() {
+        let id = obfuscator.encode(value).unwrap();
+        assert!(alphabet.validate(&id));
+        assert_eq!(obfuscator.decode(&id), Some(value));
+        assert!(seen.insert(id), "duplicate id for {value}");
+    }
+    assert_eq!(
+        obfuscator.encode(obfuscator.capacity()),
+        Err(CounterOverflow)
+    );
+}
+
+#[test]
+fn bijective_over_whole_space() {
+    check_bijection(&Obfuscator::new(&alphabet::HEX, 3, 1), &alphabet::HEX);
+    check_bijection(&Obfuscator::new(&alphabet::HEX, 3, 2), &alphabet::HEX);
+    // Not a power of two, so cycle walking is needed
+    let abc = Alphabet::new(['a', 'b', 'c']);
+    check_bijection(&Obfuscator::new(&abc, 5, 7), &abc);
+    check_bijection(&Obfuscator::new(&alphabet::BASE58, 2, 7), &alphabet::BASE58);
+    check_bijection(&Obfuscator::new(&abc, 1, 7), &abc);
+    check_bijection(&Obfuscator::new(&abc, 0, 7), &abc);
+}
+
+#[test]
+fn round_trip_large_space() {
+    let obfuscator = Obfuscator::new(&alphabet::DEFAULT, 21, 0xdead_beef);
+    assert_eq!(obfuscator.capacity(), 1 << 126);
+    for value in (0..1000).chain([u64::MAX as u128, (1 << 126) - 1]) {
+        let id = obfuscator.encode(value).unwrap();
+        assert_eq!(id.len(), 21);
+        assert_eq!(obfuscator.decode(&id), Some(value));
+    }
+}
+
+#[test]
+fn sequential_values_look_random() {
+    let obfuscator = Obfuscator::new(&alphabet::BASE58, 10, 42);
+    let ids: Vec<String> = (0..100).map(|i| obfuscator.encode(i).unwrap()).collect();
+    let mut sorted = ids.clone();
+    sorted.sort();
+    assert_ne!(ids, sorted);
+    // Neighboring values don't share a prefix
+    let shared = ids.windows(2).filter(|w| w[0][..2] == w[1][..2]).count();
+    assert!(shared < 5);
+}
+
+#[test]
+fn invalid_ids_are_rejected() {
+    let obfuscator = Obfuscator::new(&alphabet::HEX, 8, 1);
+    assert_eq!(obfuscator.decode("0123456"), None);
+    assert_eq!(obfuscator.decode("012345678"), None);
+    assert_eq!(obfuscator.decode("0123456g"), None);
+}
+
+#[test]
+fn generator_shortcut_matches_obfuscator() {
+    // No random data is needed
+    let gen = Generator::new(12, &alphabet::BASE62, SequenceRng::new(&[]));
+    let obfuscator = gen.obfuscator(99);
+    for i in 0..100 {
+        assert_eq!(gen.gen_obfuscated_sequence(99, i), obfuscator.encode(i));
+    }
+}
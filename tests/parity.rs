@@ -0,0 +1,26 @@
+//! Ids with a parity character, to detect mistyped characters.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+mod common;
+
+use randoid::{alphabet::HEX, Generator};
+
+#[test]
+fn parity_detects_any_single_change() {
+    let mut gen = Generator::new(5, &HEX, common::counting_rng());
+    for _ in 0..40 {
+        let id: Vec<char> = gen.gen_with_parity_bit().chars().collect();
+        assert_eq!(id.len(), 6);
+        assert!(HEX.verify_parity(&id.iter().collect::<String>()));
+        for pos in 0..id.len() {
+            for &c in HEX.as_chars() {
+                if c == id[pos] {
+                    continue;
+                }
+                let mut changed = id.clone();
+                changed[pos] = c;
+                assert!(!HEX.verify_parity(&changed.iter().collect::<String>()));
+            }
+        }
+    }
+}
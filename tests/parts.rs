@@ -0,0 +1,24 @@
+//! Splitting ids into groups of characters.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+mod common;
+
+use randoid::{Alphabet, Generator};
+
+#[test]
+fn parts_join_to_gen() {
+    let alphabet = Alphabet::new(['a', 'é', '€', '🦀']);
+    for group_len in 1..12 {
+        let mut expected = Generator::new(10, &alphabet, common::counting_rng());
+        let mut gen = Generator::new(10, &alphabet, common::counting_rng());
+        for _ in 0..5 {
+            let parts = gen.parts(group_len);
+            let groups: Vec<&str> = parts.iter().collect();
+            assert_eq!(groups.len(), 10usize.div_ceil(group_len));
+            assert!(groups[..groups.len() - 1]
+                .iter()
+                .all(|g| g.chars().count() == group_len));
+            assert_eq!(groups.concat(), expected.gen());
+        }
+    }
+}
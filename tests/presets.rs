@@ -0,0 +1,35 @@
+//! Which alphabet presets are available.
+
+use randoid::alphabet;
+
+#[test]
+fn core_presets_are_always_available() {
+    assert_eq!(alphabet::DEFAULT.len(), 64);
+    assert_eq!(alphabet::HEX.len(), 16);
+    assert_eq!(
+        alphabet::presets::DEFAULT.as_chars(),
+        alphabet::DEFAULT.as_chars()
+    );
+    assert_eq!(alphabet::presets::HEX.as_chars(), alphabet::HEX.as_chars());
+    assert_eq!(alphabet::presets::BASE58.len(), 58);
+}
+
+#[cfg(feature = "presets")]
+#[test]
+fn specialized_presets_with_feature() {
+    use alphabet::presets::{BASE36, CROCKFORD, DNA, GEOHASH};
+
+    assert_eq!(CROCKFORD.len(), 32);
+    assert_eq!(GEOHASH.len(), 32);
+    assert_eq!(BASE36.len(), 36);
+    assert_eq!(DNA.as_chars(), &['A', 'C', 'G', 'T']);
+    assert_eq!(alphabet::CROCKFORD.as_chars(), CROCKFORD.as_chars());
+    for alphabet in [
+        &CROCKFORD.as_chars()[..],
+        GEOHASH.as_chars(),
+        BASE36.as_chars(),
+        DNA.as_chars(),
+    ] {
+        assert!(alphabet.iter().all(char::is_ascii_alphanumeric));
+    }
+}
@@ -0,0 +1,184 @@
+//! Property tests for invariants that should hold for any alphabet, size, and seed.
+#![cfg(not(target_arch = "wasm32"))]
+
+use proptest::prelude::*;
+use rand::{SeedableRng, TryRngCore};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{rngs::SequenceRng, Alphabet, Generator};
+
+/// Strategy for an alphabet of `N` distinct printable ASCII characters, in random order
+fn ascii_alphabet<const N: usize>() -> impl Strategy<Value = Alphabet<N>> {
+    let printable: Vec<char> = (b' '..=b'~').map(char::from).collect();
+    proptest::sample::subsequence(printable, N)
+        .prop_shuffle()
+        .prop_map(|chars| Alphabet::new(chars.try_into().unwrap()))
+}
+
+/// Strategy for the size of an id
+///
+/// Includes sizes larger than the internal buffer, so that ids which need
+/// more than one batch of random data are covered.
+fn id_size() -> impl Strategy<Value = usize> {
+    prop_oneof![0usize..=32, 33usize..=300]
+}
+
+fn check_id<const N: usize>(alphabet: &Alphabet<N>, size: usize, seed: u64) {
+    let rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+    let id = Generator::new(size, alphabet, rng).gen();
+    assert_eq!(id.chars().count(), size);
+    assert!(
+        alphabet.validate(&id),
+        "{id:?} has characters not in {alphabet:?}"
+    );
+}
+
+proptest! {
+    #[test]
+    fn ids_use_hex_sized_alphabet(alphabet in ascii_alphabet::<16>(), size in id_size(), seed: u64) {
+        check_id(&alphabet, size, seed);
+    }
+
+    #[test]
+    fn ids_use_default_sized_alphabet(alphabet in ascii_alphabet::<64>(), size in id_size(), seed: u64) {
+        check_id(&alphabet, size, seed);
+    }
+
+    #[test]
+    fn ids_use_tiny_alphabet(alphabet in ascii_alphabet::<2>(), size in id_size(), seed: u64) {
+        check_id(&alphabet, size, seed);
+    }
+
+    #[test]
+    fn ids_use_base58_sized_alphabet(alphabet in ascii_alphabet::<58>(), size in id_size(), seed: u64) {
+        check_id(&alphabet, size, seed);
+    }
+
+    #[test]
+    fn ids_use_odd_sized_alphabet(alphabet in ascii_alphabet::<3>(), size in id_size(), seed: u64) {
+        check_id(&alphabet, size, seed);
+    }
+
+    #[test]
+    fn ids_use_largest_alphabet(size in id_size(), seed: u64) {
+        let chars: [char; 255] = randoid::alphabet::char_range('\u{100}', '\u{1fe}');
+        check_id(&Alphabet::new(chars), size, seed);
+    }
+
+    #[test]
+    fn same_seed_same_id(size in id_size(), seed: u64) {
+        let mut a = Generator::new(size, &randoid::alphabet::DEFAULT, Xoshiro256PlusPlus::seed_from_u64(seed));
+        let mut b = Generator::new(size, &randoid::alphabet::DEFAULT, Xoshiro256PlusPlus::seed_from_u64(seed));
+        prop_assert_eq!(a.gen(), b.gen());
+    }
+
+    #[test]
+    fn lookup_table_matches_alphabet(alphabet in ascii_alphabet::<58>()) {
+        let table = alphabet.ascii_lookup_table();
+        for b in 0..128u8 {
+            let expected = alphabet.as_chars().iter().position(|&c| c == char::from(b));
+            prop_assert_eq!(table[usize::from(b)], expected.map_or(0xFF, |i| i as u8));
+        }
+    }
+
+    #[test]
+    fn fast_paths_match_write_to(size in id_size(), seed: u64) {
+        check_fast_paths(&randoid::alphabet::DEFAULT, size, seed)?;
+        check_fast_paths(&randoid::alphabet::BASE58, size, seed)?;
+        check_fast_paths(&Alphabet::new(['a', 'é', '€', '🦀', '!']), size, seed)?;
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_matches_gen(size in 0usize..40, seed: u64, chunk in 1usize..10) {
+        let alphabet = Alphabet::new(['a', 'é', '€', '🦀', '!']);
+        let rng = || Xoshiro256PlusPlus::seed_from_u64(seed);
+        let mut expected = Vec::new();
+        let mut gen = Generator::new(size, &alphabet, rng());
+        for _ in 0..5 {
+            expected.extend_from_slice(gen.gen().as_bytes());
+            expected.push(b'\n');
+        }
+
+        let mut reader = Generator::new(size, &alphabet, rng()).into_reader(Some(b'\n'));
+        let mut actual = Vec::new();
+        let mut buf = vec![0; chunk];
+        while actual.len() < expected.len() {
+            let n = std::io::Read::read(&mut reader, &mut buf).unwrap();
+            prop_assert_eq!(n, chunk);
+            actual.extend_from_slice(&buf);
+        }
+        actual.truncate(expected.len());
+        prop_assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn masked_matches_rejection_for_power_of_two(bytes in proptest::collection::vec(any::<u8>(), 0..300)) {
+        check_masked_matches_rejection(&randoid::alphabet::DEFAULT, &bytes)?;
+        check_masked_matches_rejection(&randoid::alphabet::HEX, &bytes)?;
+        check_masked_matches_rejection(&Alphabet::new(['0', '1']), &bytes)?;
+        let chars: [char; 128] = randoid::alphabet::char_range('\u{100}', '\u{17f}');
+        check_masked_matches_rejection(&Alphabet::new(chars), &bytes)?;
+    }
+}
+
+/// Check that the specialized `String` and `Vec<u8>` paths, preparing an id ahead of time, and
+/// using the thread local buffer, produce the same id as `write_to`
+fn check_fast_paths<const N: usize>(
+    alphabet: &Alphabet<N>,
+    size: usize,
+    seed: u64,
+) -> Result<(), TestCaseError> {
+    let gen = || Generator::new(size, alphabet, Xoshiro256PlusPlus::seed_from_u64(seed));
+    let mut written = String::new();
+    gen().write_to(&mut written).unwrap();
+
+    let mut pushed = String::new();
+    gen().push_to(&mut pushed);
+    prop_assert_eq!(&pushed, &written);
+
+    let mut appended = String::from("prefix:");
+    gen().push_to(&mut appended);
+    prop_assert_eq!(appended.strip_prefix("prefix:"), Some(written.as_str()));
+
+    let mut bytes = Vec::new();
+    gen().extend_bytes(&mut bytes);
+    prop_assert_eq!(bytes, written.as_bytes());
+
+    prop_assert_eq!(gen().prepare().finish(alphabet), written.as_str());
+    #[cfg(feature = "std")]
+    prop_assert_eq!(gen().gen_tls(), written.as_str());
+    prop_assert_eq!(gen().gen(), written);
+    Ok(())
+}
+
+/// Straightforward rejection sampling, one byte at a time
+///
+/// Reference implementation for `masked_matches_rejection_for_power_of_two`.
+fn rejection_sample<const N: usize>(alphabet: &Alphabet<N>, bytes: &[u8]) -> String {
+    let mask = N.next_power_of_two() - 1;
+    bytes
+        .iter()
+        .map(|&b| usize::from(b) & mask)
+        .filter(|&idx| idx < N)
+        .map(|idx| alphabet.as_chars()[idx])
+        .collect()
+}
+
+/// Check that, given the same random bytes, the generator produces the same id as
+/// `rejection_sample`
+///
+/// With an alphabet whose size is a power of two, nothing is ever rejected, so the generator
+/// should use exactly one byte per character, in order.
+fn check_masked_matches_rejection<const N: usize>(
+    alphabet: &Alphabet<N>,
+    bytes: &[u8],
+) -> Result<(), TestCaseError> {
+    assert!(N.is_power_of_two());
+    let expected = rejection_sample(alphabet, bytes);
+    prop_assert_eq!(expected.chars().count(), bytes.len());
+
+    let mut gen = Generator::new(bytes.len(), alphabet, SequenceRng::new(bytes).unwrap_err());
+    prop_assert_eq!(gen.gen(), expected);
+    prop_assert!(gen.into_random().0.remaining().is_empty());
+    Ok(())
+}
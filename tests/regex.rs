@@ -0,0 +1,25 @@
+//! Generating ids that match a regular expression.
+#![cfg(feature = "regex")]
+
+use rand::TryRngCore;
+use randoid::{alphabet::HEX, rngs::SequenceRng, Generator};
+use regex::Regex;
+
+#[test]
+fn returns_first_matching_id() {
+    // "0123", "4567", "89ab", "cdef"
+    let bytes: Vec<u8> = (0..16).collect();
+    let mut gen = Generator::new(4, &HEX, SequenceRng::new(&bytes).unwrap_err());
+    let re = Regex::new("^[0-9]+[a-f]+$").unwrap();
+    assert_eq!(gen.gen_matching_regex(&re, 4).as_deref(), Some("89ab"));
+}
+
+#[test]
+fn gives_up_after_max_tries() {
+    let bytes: Vec<u8> = (0..16).collect();
+    let mut gen = Generator::new(4, &HEX, SequenceRng::new(&bytes).unwrap_err());
+    let re = Regex::new("f$").unwrap();
+    assert_eq!(gen.gen_matching_regex(&re, 3), None);
+    // The fourth id would have matched
+    assert_eq!(gen.gen(), "cdef");
+}
@@ -0,0 +1,57 @@
+//! Ids with a keyed verification tag.
+#![cfg(feature = "blake3")]
+
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{alphabet::BASE58, Generator};
+
+const KEY: [u8; 32] = *b"an example key, 32 bytes long!!!";
+
+fn gen() -> Generator<'static, Xoshiro256PlusPlus, 58> {
+    Generator::new(12, &BASE58, Xoshiro256PlusPlus::seed_from_u64(471))
+}
+
+#[test]
+fn tagged_ids_round_trip() {
+    let mut gen = gen();
+    for tag_len in 1..20 {
+        let id = gen.gen_with_tag(&KEY, tag_len);
+        assert_eq!(id.len(), 12 + tag_len);
+        assert!(BASE58.validate(&id));
+        assert!(gen.verify_tag(&id, &KEY, tag_len), "{id} failed to verify");
+        assert!(!gen.verify_tag(&id, &KEY, tag_len + 1));
+    }
+}
+
+#[test]
+fn tag_is_deterministic() {
+    let mut a = gen();
+    let mut b = gen();
+    assert_eq!(a.gen_with_tag(&KEY, 8), b.gen_with_tag(&KEY, 8));
+    // The same random part with a longer tag starts with the same tag characters
+    let (short, long) = (a.gen_with_tag(&KEY, 4), b.gen_with_tag(&KEY, 8));
+    assert!(long.starts_with(&short));
+}
+
+#[test]
+fn tampering_is_detected() {
+    let mut gen = gen();
+    let id: Vec<char> = gen.gen_with_tag(&KEY, 10).chars().collect();
+    for pos in 0..id.len() {
+        let mut tampered = id.clone();
+        tampered[pos] = if id[pos] == 'z' { 'y' } else { 'z' };
+        let tampered: String = tampered.into_iter().collect();
+        assert!(!gen.verify_tag(&tampered, &KEY, 10), "{tampered} verified");
+    }
+    let id: String = id.into_iter().collect();
+    assert!(gen.verify_tag(&id, &KEY, 10));
+    assert!(!gen.verify_tag(&id, &[0; 32], 10));
+    // Shortening the tag makes a valid shorter tag, but it still has the wrong length
+    assert!(gen.verify_tag(&id[..id.len() - 1], &KEY, 9));
+    assert!(!gen.verify_tag(&id[..id.len() - 1], &KEY, 10));
+    assert!(!gen.verify_tag(&format!("{id}a"), &KEY, 10));
+    // No tag at all, or characters outside the alphabet
+    assert!(!gen.verify_tag(&id[..12], &KEY, 10));
+    assert!(!gen.verify_tag(&id[..12], &KEY, 0));
+    assert!(!gen.verify_tag(&id.replace(&id[..1], "0"), &KEY, 10));
+}
@@ -0,0 +1,10 @@
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{Alphabet, Generator};
+
+fn main() {
+    let alphabet = Alphabet::new(['a', 'é']);
+    let rng = Xoshiro256PlusPlus::seed_from_u64(0);
+    let mut gen = Generator::new_ascii(8, &alphabet, rng);
+    gen.write_bytes(&mut [0; 8]);
+}
@@ -0,0 +1,35 @@
+//! Generating batches of unique, sorted ids.
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use rand::{SeedableRng, TryRngCore};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use randoid::{alphabet::HEX, rngs::SequenceRng, Generator, NotEnoughUnique};
+
+#[test]
+fn unique_sorted_skips_duplicates() {
+    let bytes = [4, 5, 0, 1, 4, 5, 2, 3, 0, 1, 6, 7];
+    let mut gen = Generator::new(2, &HEX, SequenceRng::new(&bytes).unwrap_err());
+    assert_eq!(
+        gen.gen_unique_sorted(4, 6).unwrap(),
+        ["01", "23", "45", "67"]
+    );
+
+    let mut gen = Generator::new(2, &HEX, SequenceRng::new(&bytes).unwrap_err());
+    assert_eq!(
+        gen.gen_unique_sorted(4, 5),
+        Err(NotEnoughUnique { found: 3 })
+    );
+}
+
+#[test]
+fn sorted_unique_in_range_constrains_first_character() {
+    let rng = Xoshiro256PlusPlus::seed_from_u64(464);
+    let mut gen = Generator::new(3, &HEX, rng);
+    let ids = gen.gen_k_sorted_unique_in_range(40, 3..7, 200).unwrap();
+    assert_eq!(ids.len(), 40);
+    for id in &ids {
+        let first = HEX.as_chars().iter().position(|&c| id.starts_with(c));
+        assert!(matches!(first, Some(3..=6)), "{id} starts outside of 3..7");
+    }
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,102 @@
+//! Generating UUIDs with a generator's rng.
+
+use rand::TryRngCore;
+use randoid::{rngs::SequenceRng, Generator};
+
+/// Check that `uuid` has the canonical 8-4-4-4-12 format, and the version and variant of a
+/// version 4 UUID
+fn check_hyphenated(uuid: &str) {
+    assert_eq!(uuid.len(), 36, "{uuid:?}");
+    for (i, c) in uuid.char_indices() {
+        if matches!(i, 8 | 13 | 18 | 23) {
+            assert_eq!(c, '-', "{uuid:?}");
+        } else {
+            assert!(matches!(c, '0'..='9' | 'a'..='f'), "{uuid:?}");
+        }
+    }
+    assert_eq!(&uuid[14..15], "4", "wrong version in {uuid:?}");
+    assert!(
+        matches!(&uuid[19..20], "8" | "9" | "a" | "b"),
+        "wrong variant in {uuid:?}"
+    );
+}
+
+#[test]
+fn hyphenated_bits_are_set_regardless_of_random_data() {
+    for byte in [0x00, 0xff, 0x5a] {
+        let bytes = [byte; 16];
+        let uuid = Generator::with_random(SequenceRng::new(&bytes).unwrap_err())
+            .gen_hyphenated_uuid_string();
+        check_hyphenated(&uuid);
+    }
+    let bytes = [0; 16];
+    assert_eq!(
+        Generator::with_random(SequenceRng::new(&bytes).unwrap_err()).gen_hyphenated_uuid_string(),
+        "00000000-0000-4000-8000-000000000000"
+    );
+}
+
+#[test]
+fn uuid_bytes_have_version_and_variant() {
+    for byte in [0x00, 0xff, 0x5a] {
+        let bytes = [byte; 16];
+        let uuid = Generator::with_random(SequenceRng::new(&bytes).unwrap_err()).gen_uuid_bytes();
+        assert_eq!(uuid[6] >> 4, 4);
+        assert_eq!(uuid[8] & 0xc0, 0x80);
+        // The other bits come from the rng
+        assert_eq!(uuid[6] & 0x0f, byte & 0x0f);
+        assert_eq!(uuid[8] & 0x3f, byte & 0x3f);
+        assert!(uuid
+            .iter()
+            .enumerate()
+            .all(|(i, &b)| matches!(i, 6 | 8) || b == byte));
+    }
+}
+
+#[cfg(feature = "std-rand")]
+#[test]
+fn random_hyphenated_uuids() {
+    let mut gen = Generator::with_random(rand::rng());
+    for _ in 0..100 {
+        check_hyphenated(&gen.gen_hyphenated_uuid_string());
+    }
+}
+
+#[cfg(feature = "uuid")]
+fn check_version_and_variant(uuid: uuid::Uuid) {
+    let bytes = uuid.as_bytes();
+    assert_eq!(bytes[6] >> 4, 4, "wrong version in {uuid}");
+    assert_eq!(bytes[8] & 0xc0, 0x80, "wrong variant in {uuid}");
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn bits_are_set_regardless_of_random_data() {
+    for byte in [0x00, 0xff, 0x5a] {
+        let bytes = [byte; 16];
+        let uuid = Generator::with_random(SequenceRng::new(&bytes).unwrap_err()).gen_uuid();
+        check_version_and_variant(uuid);
+    }
+}
+
+#[cfg(all(feature = "uuid", feature = "std-rand"))]
+#[test]
+fn random_uuids() {
+    let mut gen = Generator::with_random(rand::rng());
+    for _ in 0..100 {
+        check_version_and_variant(gen.gen_uuid());
+    }
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn hyphenated_matches_uuid() {
+    let bytes: Vec<u8> = (0..32u8).map(|i| i.wrapping_mul(37)).collect();
+    let mut gen = Generator::with_random(SequenceRng::new(&bytes).unwrap_err());
+    let uuid = gen.gen_uuid();
+    let mut gen = Generator::with_random(SequenceRng::new(&bytes).unwrap_err());
+    assert_eq!(
+        gen.gen_hyphenated_uuid_string(),
+        uuid.hyphenated().to_string()
+    );
+}
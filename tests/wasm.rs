@@ -0,0 +1,18 @@
+//! Generating ids in a JavaScript environment.
+//!
+//! Run with `wasm-pack test --node --features wasm`, or `--headless --firefox` instead of `--node`
+//! to run in a browser.
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use randoid::{alphabet::DEFAULT, randoid, Generator};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn generates_id() {
+    let mut gen = Generator::with_wasm_rng();
+    let id = gen.gen();
+    assert_eq!(id.len(), 21);
+    assert!(DEFAULT.validate(&id));
+    assert_ne!(id, gen.gen());
+    assert_ne!(randoid(), randoid());
+}